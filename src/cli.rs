@@ -1,23 +1,140 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use clap_verbosity_flag::Verbosity;
 
+const EXIT_CODES: &str = "Exit codes:
+  0  no failing changes
+  1  error, for example an unreadable Cargo.toml
+  2  invalid arguments
+  3  Cargo.lock source or checksum changes
+  4  risky version requirements found by `lint`
+  5  Cargo.toml changes matching a `--fail-on` policy
+
+Where several apply, the lowest non-zero code wins, so Cargo.lock supply-chain changes exit with
+3 even when a `--fail-on` policy also matches.";
+
 #[derive(Parser)]
-#[clap(author,version,about,long_about=None)]
+#[clap(author,version,about,long_about=None,after_help=EXIT_CODES)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Cli {
     /// verbosity
     #[clap(flatten)]
     pub verbose: Verbosity,
 
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+
     /// repo path
-    pub repo_path: PathBuf,
+    #[clap(required = true)]
+    pub repo_path: Option<PathBuf>,
+
+    /// hide requirements rewritten without changing the versions they match
+    #[clap(long)]
+    pub hide_notation_changes: bool,
+
+    /// use words in place of emoji in text output; the default when stdout is not a UTF-8 terminal
+    #[clap(long, global = true, conflicts_with = "emoji")]
+    pub plain: bool,
+
+    /// use emoji, even when stdout is not a UTF-8 terminal
+    #[clap(long, global = true)]
+    pub emoji: bool,
+
+    /// exit with code 5 when Cargo.toml includes this kind of change; repeat for several
+    #[clap(long, value_enum)]
+    pub fail_on: Vec<FailOn>,
+
+    /// TOML template for text report lines, header and footer
+    #[clap(long)]
+    pub template: Option<PathBuf>,
+
+    /// output format
+    #[clap(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// text report, with a line for each change, formatted by `--template`
+    Text,
+
+    /// every Cargo.toml and Cargo.lock change, matching `schema/changes.schema.json`
+    Json,
+
+    /// table of Cargo.toml dependency changes for each section, for pull request descriptions
+    Markdown,
+
+    /// conventional commit message, for commitizen style commits
+    CommitMessage,
+
+    /// GitHub Actions workflow commands, annotating Cargo.toml lines
+    Github,
+
+    /// GitLab Code Quality JSON report
+    Gitlab,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Flag risky version requirements in the working tree Cargo.toml
+    Lint {
+        /// repo path
+        repo_path: PathBuf,
+
+        /// only lint dependencies added, or with a changed requirement, since the last commit
+        #[clap(long)]
+        changed: bool,
+    },
+
+    /// Add Cargo.toml dependency changes to the Unreleased section of the changelog
+    Changelog {
+        /// repo path
+        repo_path: PathBuf,
+
+        /// changelog path, relative to the repo
+        #[clap(long, default_value = "CHANGELOG.md")]
+        changelog: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum FailOn {
+    /// requirement moved by a major version
+    Major,
+
+    /// requirement moved by a minor version or more
+    Minor,
+
+    /// requirement moved by a patch version or more
+    Patch,
+
+    /// requirement lowered to match only older versions
+    Downgrade,
+
+    /// dependency added
+    Add,
+
+    /// dependency removed
+    Remove,
+
+    /// git dependency added, or registry dependency switched to git
+    GitSource,
 }
 
 #[cfg(test)]
 mod tests {
     #[test]
     fn cli_tests() {
-        trycmd::TestCases::new().case("tests/cmd/*.toml");
+        // `.trycmd` cases set up a repo with git, which trycmd needs the full path to
+        let git = std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default())
+            .map(|directory| directory.join("git"))
+            .find(|path| path.is_file())
+            .expect("git should be on the `PATH`");
+
+        trycmd::TestCases::new()
+            .register_bin("git", git)
+            .case("tests/cmd/*.toml")
+            .case("tests/cmd/*.trycmd");
     }
 }
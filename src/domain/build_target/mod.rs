@@ -0,0 +1,300 @@
+#[cfg(test)]
+mod tests;
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::Deserialize;
+
+use super::style::{Mark, Style};
+
+/// Cargo.toml build target tables: `[lib]`, `[[bin]]`, `[[example]]`, `[[test]]` and `[[bench]]`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Lib,
+    Bin,
+    Example,
+    Test,
+    Bench,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            Kind::Lib => "lib",
+            Kind::Bin => "bin",
+            Kind::Example => "example",
+            Kind::Test => "test",
+            Kind::Bench => "bench",
+        };
+        write!(f, "{label}")
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(serde::Serialize))]
+#[serde(rename_all = "kebab-case")]
+pub struct Target {
+    name: Option<String>,
+    path: Option<String>,
+    crate_type: Option<Vec<String>>,
+    proc_macro: Option<bool>,
+    harness: Option<bool>,
+    required_features: Option<Vec<String>>,
+}
+
+impl Target {
+    /// Key used to match a target across revisions.  Falls back to the path for (auto-named)
+    /// targets without an explicit name.
+    fn key(&self) -> Option<&str> {
+        self.name.as_deref().or(self.path.as_deref())
+    }
+
+    fn fmt_list(value: Option<&Vec<String>>) -> String {
+        match value {
+            Some(list) => format!("[{}]", list.join(", ")),
+            None => String::from("default"),
+        }
+    }
+
+    fn fmt_flag(value: Option<bool>) -> String {
+        match value {
+            Some(flag) => format!("{flag}"),
+            None => String::from("default"),
+        }
+    }
+
+    /// Pairs of field label, previous value and current value, for fields which affect what gets
+    /// shipped and whose value changed
+    fn field_changes(&self, previous: &Self) -> Vec<(&'static str, String, String)> {
+        let mut result = Vec::new();
+        if self.crate_type != previous.crate_type {
+            result.push((
+                "crate-type",
+                Self::fmt_list(previous.crate_type.as_ref()),
+                Self::fmt_list(self.crate_type.as_ref()),
+            ));
+        }
+        if self.proc_macro != previous.proc_macro {
+            result.push((
+                "proc-macro",
+                Self::fmt_flag(previous.proc_macro),
+                Self::fmt_flag(self.proc_macro),
+            ));
+        }
+        if self.harness != previous.harness {
+            result.push((
+                "harness",
+                Self::fmt_flag(previous.harness),
+                Self::fmt_flag(self.harness),
+            ));
+        }
+        if self.required_features != previous.required_features {
+            result.push((
+                "required-features",
+                Self::fmt_list(previous.required_features.as_ref()),
+                Self::fmt_list(self.required_features.as_ref()),
+            ));
+        }
+        result
+    }
+}
+
+/// All build targets declared in a Cargo.toml file
+#[derive(Debug, Default, PartialEq)]
+pub struct Targets {
+    pub lib: Option<Target>,
+    pub bin: Vec<Target>,
+    pub example: Vec<Target>,
+    pub test: Vec<Target>,
+    pub bench: Vec<Target>,
+}
+
+/// What happened to a build target between two Cargo.toml revisions
+#[derive(Debug, PartialEq)]
+pub enum TargetAction {
+    Added,
+    Removed,
+
+    /// Renamed to the contained name
+    Renamed(String),
+
+    /// Field affecting what gets shipped changed from `previous` to `current`
+    FieldChanged {
+        field: &'static str,
+        previous: String,
+        current: String,
+    },
+}
+
+/// Build target added, removed, renamed or with a changed field between two Cargo.toml files
+#[derive(Debug, PartialEq)]
+pub struct TargetChange {
+    pub kind: Kind,
+
+    /// Target name, or path for auto-named targets.  `None` for an unnamed `[lib]`.
+    pub name: Option<String>,
+    pub action: TargetAction,
+}
+
+impl TargetChange {
+    fn new(kind: Kind, target: &Target, action: TargetAction) -> Self {
+        Self {
+            kind,
+            name: target.key().map(String::from),
+            action,
+        }
+    }
+
+    fn label(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{} target {name}", self.kind),
+            None => format!("{} target", self.kind),
+        }
+    }
+
+    /// Text report line, without a trailing newline
+    pub fn fmt_line(&self, style: Style) -> String {
+        let label = self.label();
+        match &self.action {
+            TargetAction::Added => format!("{} {label}", style.mark(Mark::Add)),
+            TargetAction::Removed => format!("{} {label}", style.mark(Mark::Remove)),
+            TargetAction::Renamed(name) => {
+                format!("{} {label} to {name}", style.mark(Mark::Rename))
+            }
+            TargetAction::FieldChanged {
+                field,
+                previous,
+                current,
+            } => format!(
+                "{} change {label} {field} from {previous} to {current}",
+                style.mark(Mark::Target)
+            ),
+        }
+    }
+}
+
+impl Targets {
+    fn get_field_changes(
+        kind: Kind,
+        current: &Target,
+        previous: &Target,
+        result: &mut Vec<TargetChange>,
+    ) {
+        for (field, previous_value, current_value) in current.field_changes(previous) {
+            result.push(TargetChange::new(
+                kind,
+                current,
+                TargetAction::FieldChanged {
+                    field,
+                    previous: previous_value,
+                    current: current_value,
+                },
+            ));
+        }
+    }
+
+    fn get_lib_changes(
+        current: Option<&Target>,
+        previous: Option<&Target>,
+        result: &mut Vec<TargetChange>,
+    ) {
+        match (current, previous) {
+            (Some(current_value), Some(previous_value)) => {
+                if current_value.name != previous_value.name {
+                    result.push(TargetChange::new(
+                        Kind::Lib,
+                        previous_value,
+                        TargetAction::Renamed(String::from(
+                            current_value.key().unwrap_or("default"),
+                        )),
+                    ));
+                }
+                Self::get_field_changes(Kind::Lib, current_value, previous_value, result);
+            }
+            (Some(current_value), None) => {
+                result.push(TargetChange::new(
+                    Kind::Lib,
+                    current_value,
+                    TargetAction::Added,
+                ));
+            }
+            (None, Some(previous_value)) => {
+                result.push(TargetChange::new(
+                    Kind::Lib,
+                    previous_value,
+                    TargetAction::Removed,
+                ));
+            }
+            (None, None) => {}
+        }
+    }
+
+    /// Matches targets by name.  Unmatched targets sharing a path across revisions are reported
+    /// as renames, rather than as an addition and a removal.
+    fn get_target_list_changes(
+        kind: Kind,
+        current: &[Target],
+        previous: &[Target],
+        result: &mut Vec<TargetChange>,
+    ) {
+        let current_by_key: BTreeMap<&str, &Target> = current
+            .iter()
+            .filter_map(|target| target.key().map(|key| (key, target)))
+            .collect();
+        let previous_by_key: BTreeMap<&str, &Target> = previous
+            .iter()
+            .filter_map(|target| target.key().map(|key| (key, target)))
+            .collect();
+
+        let mut removed: Vec<&Target> = previous_by_key
+            .iter()
+            .filter(|(key, _)| !current_by_key.contains_key(*key))
+            .map(|(_, target)| *target)
+            .collect();
+
+        for (key, current_value) in &current_by_key {
+            if let Some(previous_value) = previous_by_key.get(key) {
+                Self::get_field_changes(kind, current_value, previous_value, result);
+                continue;
+            }
+
+            let renamed_from = current_value.path.as_ref().and_then(|path| {
+                removed
+                    .iter()
+                    .position(|target| target.path.as_ref() == Some(path))
+            });
+            if let Some(index) = renamed_from {
+                let previous_value = removed.remove(index);
+                result.push(TargetChange::new(
+                    kind,
+                    previous_value,
+                    TargetAction::Renamed(String::from(*key)),
+                ));
+                Self::get_field_changes(kind, current_value, previous_value, result);
+            } else {
+                result.push(TargetChange::new(kind, current_value, TargetAction::Added));
+            }
+        }
+
+        for previous_value in removed {
+            result.push(TargetChange::new(
+                kind,
+                previous_value,
+                TargetAction::Removed,
+            ));
+        }
+    }
+
+    /// Typed record of target changes, with the `[lib]` target first, then each target list in
+    /// turn
+    pub fn changes_versus_previous_version(&self, previous: &Self) -> Vec<TargetChange> {
+        let mut result = Vec::new();
+        Self::get_lib_changes(self.lib.as_ref(), previous.lib.as_ref(), &mut result);
+        Self::get_target_list_changes(Kind::Bin, &self.bin, &previous.bin, &mut result);
+        Self::get_target_list_changes(Kind::Example, &self.example, &previous.example, &mut result);
+        Self::get_target_list_changes(Kind::Test, &self.test, &previous.test, &mut result);
+        Self::get_target_list_changes(Kind::Bench, &self.bench, &previous.bench, &mut result);
+
+        result
+    }
+}
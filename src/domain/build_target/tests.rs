@@ -0,0 +1,151 @@
+use super::{Kind, Target, TargetChange, Targets};
+use crate::domain::Style;
+
+fn print(changes: &[TargetChange]) -> String {
+    changes
+        .iter()
+        .map(|change| change.fmt_line(Style::Emoji) + "\n")
+        .collect()
+}
+
+fn bin(name: &str, path: Option<&str>) -> Target {
+    Target {
+        name: Some(String::from(name)),
+        path: path.map(String::from),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn fmt_kind_displays_expected_values() {
+    // act
+    let result = format!(
+        "{} {} {} {} {}",
+        Kind::Lib,
+        Kind::Bin,
+        Kind::Example,
+        Kind::Test,
+        Kind::Bench
+    );
+
+    // assert
+    assert_eq!(result, String::from("lib bin example test bench"));
+}
+
+#[test]
+fn changes_versus_previous_version_reports_nothing_for_unchanged_targets() {
+    // arrange
+    let current = Targets {
+        bin: vec![bin("app", Some("src/main.rs"))],
+        ..Default::default()
+    };
+    let previous = Targets {
+        bin: vec![bin("app", Some("src/main.rs"))],
+        ..Default::default()
+    };
+
+    // act
+    let result = print(&current.changes_versus_previous_version(&previous));
+
+    // assert
+    assert_eq!(result, String::new());
+}
+
+#[test]
+fn changes_versus_previous_version_reports_added_removed_and_renamed_binaries() {
+    // arrange
+    let current = Targets {
+        bin: vec![
+            bin("app", Some("src/main.rs")),
+            bin("helper", None),
+            bin("server", Some("src/bin/serve.rs")),
+        ],
+        ..Default::default()
+    };
+    let previous = Targets {
+        bin: vec![
+            bin("app", Some("src/main.rs")),
+            bin("daemon", Some("src/bin/serve.rs")),
+            bin("legacy", None),
+        ],
+        ..Default::default()
+    };
+
+    // act
+    let result = print(&current.changes_versus_previous_version(&previous));
+
+    // assert
+    assert_eq!(
+        result,
+        String::from(
+            "✨ add bin target helper\n\
+                🏷\u{fe0f} rename bin target daemon to server\n\
+                🗑\u{fe0f} remove bin target legacy\n"
+        )
+    );
+}
+
+#[test]
+fn changes_versus_previous_version_reports_target_field_changes() {
+    // arrange
+    let current = Targets {
+        lib: Some(Target {
+            crate_type: Some(vec![String::from("cdylib"), String::from("rlib")]),
+            proc_macro: Some(false),
+            ..Default::default()
+        }),
+        bench: vec![Target {
+            name: Some(String::from("parse")),
+            harness: Some(false),
+            required_features: Some(vec![String::from("bench")]),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let previous = Targets {
+        lib: Some(Target::default()),
+        bench: vec![Target {
+            name: Some(String::from("parse")),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    // act
+    let result = print(&current.changes_versus_previous_version(&previous));
+
+    // assert
+    assert_eq!(
+        result,
+        String::from(
+            "🎯 change lib target crate-type from default to [cdylib, rlib]\n\
+                🎯 change lib target proc-macro from default to false\n\
+                🎯 change bench target parse harness from default to false\n\
+                🎯 change bench target parse required-features from default to [bench]\n"
+        )
+    );
+}
+
+#[test]
+fn changes_versus_previous_version_reports_lib_addition_and_removal() {
+    // arrange
+    let with_lib = Targets {
+        lib: Some(Target {
+            name: Some(String::from("core_lib")),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let without_lib = Targets::default();
+
+    // act
+    let added = print(&with_lib.changes_versus_previous_version(&without_lib));
+    let removed = print(&without_lib.changes_versus_previous_version(&with_lib));
+
+    // assert
+    assert_eq!(added, String::from("✨ add lib target core_lib\n"));
+    assert_eq!(
+        removed,
+        String::from("🗑\u{fe0f} remove lib target core_lib\n")
+    );
+}
@@ -0,0 +1,826 @@
+#[cfg(test)]
+mod tests;
+
+use core::str;
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fmt::Write as _,
+    fs,
+};
+
+use anyhow::Context;
+use semver::VersionReq;
+use serde::Deserialize;
+
+use super::{
+    semver::Change,
+    style::{Mark, Style},
+    CargoTomlFile, SemverVersion,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct File {
+    packages: Vec<CargoLockPackage>,
+
+    /// Indices into `packages`, keyed by package name, for resolving `dependencies` entries
+    package_indices: BTreeMap<String, Vec<usize>>,
+}
+
+/// Breadth-first search tree of the lock file dependency graph, rooted at the workspace members
+struct DependencyTree {
+    roots: BTreeSet<usize>,
+
+    /// Package which first pulls in each package reachable from `roots`, keyed by index
+    parents: BTreeMap<usize, usize>,
+}
+
+/// Locked version change for a dependency listed in both the current and previous Cargo.toml
+#[derive(Debug, PartialEq)]
+pub struct LockedVersionChange {
+    pub package: String,
+
+    /// `None` for git dependencies
+    pub previous_requirement: Option<String>,
+
+    /// `None` for git dependencies
+    pub current_requirement: Option<String>,
+    pub previous_locked: semver::Version,
+    pub current_locked: semver::Version,
+}
+
+impl LockedVersionChange {
+    /// Text report line, without a trailing newline.  The requirement is listed once when only the
+    /// locked version changed.
+    pub fn fmt_line(&self, style: Style) -> String {
+        let current_requirement = File::fmt_requirement(self.current_requirement.as_deref());
+        let previous_requirement = File::fmt_requirement(self.previous_requirement.as_deref());
+        let locked = style.mark(Mark::Locked);
+        let arrow = style.mark(Mark::Arrow);
+        let Self {
+            package,
+            previous_locked,
+            current_locked,
+            ..
+        } = self;
+        if current_requirement == previous_requirement {
+            format!(
+                "{locked} {package} requirement {current_requirement}, locked {previous_locked} \
+                    {arrow} {current_locked}"
+            )
+        } else {
+            format!(
+                "{locked} {package} requirement {previous_requirement} {arrow} \
+                    {current_requirement}, locked {previous_locked} {arrow} {current_locked}"
+            )
+        }
+    }
+}
+
+/// Chain of packages, starting with a direct dependency, for report lines.  `None` for packages
+/// unreachable from the workspace members.
+fn fmt_chain(chain: Option<&[String]>, style: Style) -> String {
+    match chain {
+        Some([]) => String::from(" (direct)"),
+        Some(chain) => format!(
+            " (via {})",
+            chain.join(&format!(" {} ", style.mark(Mark::Arrow)))
+        ),
+        None => String::new(),
+    }
+}
+
+/// Package added to, removed from or changed in version in Cargo.lock, including transitive
+/// dependencies
+#[derive(Debug, PartialEq)]
+pub struct PackageChange {
+    pub package: String,
+
+    /// `None` for an added package
+    pub previous_version: Option<semver::Version>,
+
+    /// `None` for a removed package
+    pub current_version: Option<semver::Version>,
+
+    /// Most significant version component moved, and `Change::None` for added and removed
+    /// packages
+    pub change: Change,
+
+    /// Packages, starting with a direct dependency, which pull the package in.  Empty for direct
+    /// dependencies, and `None` for packages unreachable from the workspace members.
+    pub chain: Option<Vec<String>>,
+}
+
+impl PackageChange {
+    /// Value used for `direction` in JSON output
+    pub fn direction(&self) -> &'static str {
+        match (&self.previous_version, &self.current_version) {
+            (None, _) => "add",
+            (_, None) => "remove",
+            (Some(previous), Some(current)) if current < previous => "drop",
+            _ => "bump",
+        }
+    }
+
+    /// Text report line, without a trailing newline
+    pub fn fmt_line(&self, style: Style) -> String {
+        let chain = fmt_chain(self.chain.as_deref(), style);
+        let cargo_lock = style.mark(Mark::CargoLock);
+        let package = &self.package;
+        match (&self.previous_version, &self.current_version) {
+            (Some(previous), Some(current)) => format!(
+                "{} {} {package} ({cargo_lock}) from {previous} to {current}{chain}",
+                style.severity(&self.change),
+                self.direction()
+            ),
+            (None, Some(version)) => {
+                format!(
+                    "{} {package} ({cargo_lock}) {version}{chain}",
+                    style.mark(Mark::Add)
+                )
+            }
+            (Some(version), None) => format!(
+                "{} {package} ({cargo_lock}) {version}{chain}",
+                style.mark(Mark::Remove)
+            ),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+/// How a change affected a crate with more than one version in Cargo.lock
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DuplicateAction {
+    /// Crate had a single version before the change
+    Introduce,
+
+    /// Duplicate gained a version it lacked before
+    Extend,
+
+    /// Duplicate versions unchanged, or only removed
+    Keep,
+
+    /// Crate is back to a single version
+    Resolve,
+}
+
+impl DuplicateAction {
+    /// Value used for `action` in JSON output
+    pub fn id(self) -> &'static str {
+        match self {
+            DuplicateAction::Introduce => "introduce",
+            DuplicateAction::Extend => "extend",
+            DuplicateAction::Keep => "keep",
+            DuplicateAction::Resolve => "resolve",
+        }
+    }
+}
+
+/// Crate with more than one version in the current Cargo.lock, or a resolved duplicate
+#[derive(Debug, PartialEq)]
+pub struct DuplicateChange {
+    pub package: String,
+    pub action: DuplicateAction,
+
+    /// Versions in ascending order, taken from the previous Cargo.lock for resolved duplicates
+    pub versions: Vec<semver::Version>,
+
+    /// Direct dependency, added or changed in Cargo.toml, which pulls in one of the new versions
+    pub cause: Option<String>,
+}
+
+impl DuplicateChange {
+    /// Text report line, without a trailing newline
+    pub fn fmt_line(&self, style: Style) -> String {
+        let versions = self
+            .versions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let package = &self.package;
+        let cargo_lock = style.mark(Mark::CargoLock);
+        let action = self.action.id();
+        match (self.action, &self.cause) {
+            (DuplicateAction::Resolve, _) => format!(
+                "{} duplicate {package} ({cargo_lock}) {versions}",
+                style.mark(Mark::Resolve)
+            ),
+            (_, Some(cause)) => format!(
+                "{} {action} duplicate {package} ({cargo_lock}) {versions} (from Cargo.toml \
+                    change to {cause})",
+                style.mark(Mark::DuplicateAlert)
+            ),
+            (_, None) => format!(
+                "{} {action} duplicate {package} ({cargo_lock}) {versions}",
+                style.mark(Mark::Duplicate)
+            ),
+        }
+    }
+}
+
+/// Cargo.lock package field whose change is a supply-chain signal
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SupplyChainField {
+    Source,
+    Checksum,
+}
+
+impl SupplyChainField {
+    /// Value used for `field` in JSON output
+    pub fn id(self) -> &'static str {
+        match self {
+            SupplyChainField::Source => "source",
+            SupplyChainField::Checksum => "checksum",
+        }
+    }
+}
+
+/// Package source switched, or checksum changed for the same version, between two Cargo.lock
+/// files
+#[derive(Debug, PartialEq)]
+pub struct SupplyChainChange {
+    pub package: String,
+    pub version: String,
+    pub field: SupplyChainField,
+
+    /// `None` for a local path source
+    pub previous: Option<String>,
+
+    /// `None` for a local path source
+    pub current: Option<String>,
+}
+
+impl SupplyChainChange {
+    /// Source or checksum for reports, where a missing source means a local path
+    pub fn fmt_value(value: Option<&String>) -> &str {
+        value.map_or("local path", String::as_str)
+    }
+
+    /// Summary without marks, for example `change source serde 1.0.210 from <previous> to
+    /// <current>`
+    pub fn fmt_summary(&self) -> String {
+        format!(
+            "change {} {} {} from {} to {}",
+            self.field.id(),
+            self.package,
+            self.version,
+            Self::fmt_value(self.previous.as_ref()),
+            Self::fmt_value(self.current.as_ref())
+        )
+    }
+
+    /// Text report line, without a trailing newline
+    pub fn fmt_line(&self, style: Style) -> String {
+        format!(
+            "{} change {} {} ({}) {} from {} to {}",
+            style.mark(Mark::SupplyChain),
+            self.field.id(),
+            self.package,
+            style.mark(Mark::CargoLock),
+            self.version,
+            Self::fmt_value(self.previous.as_ref()),
+            Self::fmt_value(self.current.as_ref())
+        )
+    }
+}
+
+impl File {
+    pub fn new(path: &str) -> anyhow::Result<Self> {
+        let cargo_lock_str = fs::read_to_string(path)
+            .with_context(|| format!("Error opening Cargo.lock file: `{path}`"))?;
+
+        Self::new_from_str(&cargo_lock_str).with_context(|| format!("Error parsing `{path}`"))
+    }
+
+    pub fn new_from_buffer(buffer: &[u8]) -> anyhow::Result<Self> {
+        let cargo_lock_str =
+            str::from_utf8(buffer).context("Creating `CargoLockFile` from buffer")?;
+
+        Self::new_from_str(cargo_lock_str)
+    }
+
+    pub fn new_from_str(toml_str: &str) -> anyhow::Result<Self> {
+        let CargoLockFile { package } =
+            toml::from_str(toml_str).context("Creating `CargoLockFile` from str")?;
+        log::trace!("Cargo.lock packages: {package:?}");
+        let packages = package.unwrap_or_default();
+        let mut package_indices: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (index, package) in packages.iter().enumerate() {
+            package_indices
+                .entry(package.name.clone())
+                .or_default()
+                .push(index);
+        }
+
+        Ok(Self {
+            packages,
+            package_indices,
+        })
+    }
+
+    /// Version of `package` resolved in the lock file.  When the lock holds several versions of
+    /// the package, picks the highest one matching `requirement`.
+    pub fn locked_version(
+        &self,
+        package: &str,
+        requirement: Option<&str>,
+    ) -> Option<semver::Version> {
+        let requirement = requirement.and_then(|value| VersionReq::parse(value).ok());
+        let candidates = self
+            .package_indices
+            .get(package)
+            .into_iter()
+            .flatten()
+            .filter_map(|index| semver::Version::parse(&self.packages[*index].version).ok());
+
+        match requirement {
+            Some(requirement_value) => candidates
+                .filter(|version| requirement_value.matches(version))
+                .max(),
+            None => candidates.max(),
+        }
+    }
+
+    /// Resolves a `dependencies` entry, which takes the form `name`, `name version` or
+    /// `name version (source)`, to the index of the package it refers to
+    fn dependency_index(&self, dependency: &str) -> Option<usize> {
+        let mut parts = dependency.split_whitespace();
+        let name = parts.next()?;
+        let version = parts.next();
+        self.package_indices
+            .get(name)?
+            .iter()
+            .copied()
+            .find(|index| version.is_none_or(|value| self.packages[*index].version == value))
+    }
+
+    /// Breadth-first search of the lock file dependency graph, starting from the workspace members
+    /// of `manifest`.  Path dependencies outside the workspace also lack a source, so are not
+    /// treated as members.
+    fn dependency_tree(&self, manifest: &CargoTomlFile) -> DependencyTree {
+        let roots: BTreeSet<usize> = self
+            .packages
+            .iter()
+            .enumerate()
+            .filter(|(_, val)| val.source.is_none() && manifest.is_workspace_member(&val.name))
+            .map(|(index, _)| index)
+            .collect();
+
+        let mut parents: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut visited = roots.clone();
+        let mut queue: VecDeque<usize> = roots.iter().copied().collect();
+        while let Some(current) = queue.pop_front() {
+            for dependency in self.packages[current].dependencies.iter().flatten() {
+                if let Some(dependency_index) = self.dependency_index(dependency) {
+                    if visited.insert(dependency_index) {
+                        parents.insert(dependency_index, current);
+                        queue.push_back(dependency_index);
+                    }
+                }
+            }
+        }
+
+        DependencyTree { roots, parents }
+    }
+
+    /// Shortest chain of packages from a workspace member to the package at `index`.  The chain
+    /// starts with a direct dependency and excludes the package itself, so is empty for direct
+    /// dependencies.  Returns `None` for workspace members and packages unreachable from them.
+    fn dependency_chain(&self, tree: &DependencyTree, index: usize) -> Option<Vec<String>> {
+        if tree.roots.contains(&index) {
+            return None;
+        }
+
+        let mut chain = Vec::new();
+        let mut current = *tree.parents.get(&index)?;
+        while let Some(parent) = tree.parents.get(&current) {
+            chain.push(self.packages[current].name.clone());
+            current = *parent;
+        }
+        chain.reverse();
+
+        Some(chain)
+    }
+
+    /// Package versions in the lock file, in ascending order and keyed by package name, excluding
+    /// the workspace members in `tree`
+    fn package_versions(
+        &self,
+        tree: &DependencyTree,
+    ) -> BTreeMap<&str, Vec<(semver::Version, usize)>> {
+        let mut result: BTreeMap<&str, Vec<(semver::Version, usize)>> = BTreeMap::new();
+        for (index, package) in self.packages.iter().enumerate() {
+            if tree.roots.contains(&index) {
+                continue;
+            }
+            let Ok(version) = semver::Version::parse(&package.version) else {
+                log::warn!(
+                    "Unexpected version `{}` found for `{}` in Cargo.lock",
+                    package.version,
+                    package.name
+                );
+                continue;
+            };
+            result
+                .entry(package.name.as_str())
+                .or_default()
+                .push((version, index));
+        }
+        for versions in result.values_mut() {
+            versions.sort();
+        }
+
+        result
+    }
+
+    fn version_change(
+        &self,
+        tree: &DependencyTree,
+        name: &str,
+        current: &(semver::Version, usize),
+        previous: &semver::Version,
+    ) -> PackageChange {
+        let (current_version, current_index) = current;
+        // Requirements cannot include build metadata, which is irrelevant to precedence anyway
+        let exact_requirement = |version: &semver::Version| {
+            SemverVersion::new(&format!(
+                "={}",
+                semver::Version {
+                    build: semver::BuildMetadata::EMPTY,
+                    ..version.clone()
+                }
+            ))
+        };
+        let change = match (
+            exact_requirement(current_version),
+            exact_requirement(previous),
+        ) {
+            (Ok(current_requirement), Ok(previous_requirement)) => current_requirement
+                .change_type(&previous_requirement)
+                .unwrap_or(Change::Unknown),
+            _ => Change::Unknown,
+        };
+
+        PackageChange {
+            package: String::from(name),
+            previous_version: Some(previous.clone()),
+            current_version: Some(current_version.clone()),
+            change,
+            chain: self.dependency_chain(tree, *current_index),
+        }
+    }
+
+    /// Every package added, removed or changed in version in the lock file, including transitive
+    /// dependencies, with the chain of dependencies, starting with a direct dependency, which
+    /// pulls the package in.  Version changes to direct dependencies already listed by
+    /// `locked_version_changes` are skipped.
+    pub fn package_changes(
+        &self,
+        current_manifest: &CargoTomlFile,
+        previous: &Self,
+        previous_manifest: &CargoTomlFile,
+    ) -> Vec<PackageChange> {
+        let mut result = Vec::new();
+
+        let locked_version_changes =
+            self.locked_version_changes(current_manifest, previous, previous_manifest);
+
+        let current_tree = self.dependency_tree(current_manifest);
+        let previous_tree = previous.dependency_tree(previous_manifest);
+        let current_versions = self.package_versions(&current_tree);
+        let previous_versions = previous.package_versions(&previous_tree);
+        let names: BTreeSet<&str> = current_versions
+            .keys()
+            .chain(previous_versions.keys())
+            .copied()
+            .collect();
+
+        for name in names {
+            let current = current_versions.get(name).map_or(&[][..], Vec::as_slice);
+            let previous_packages = previous_versions.get(name).map_or(&[][..], Vec::as_slice);
+            let added: Vec<&(semver::Version, usize)> = current
+                .iter()
+                .filter(|(version, _)| !previous_packages.iter().any(|(val, _)| val == version))
+                .collect();
+            let removed: Vec<&(semver::Version, usize)> = previous_packages
+                .iter()
+                .filter(|(version, _)| !current.iter().any(|(val, _)| val == version))
+                .collect();
+
+            if let ([current_value], [(previous_version, _)]) = (&added[..], &removed[..]) {
+                let (current_version, _) = current_value;
+                if locked_version_changes.iter().any(|change| {
+                    change.package == name
+                        && change.previous_locked == *previous_version
+                        && change.current_locked == *current_version
+                }) {
+                    continue;
+                }
+                result.push(self.version_change(
+                    &current_tree,
+                    name,
+                    current_value,
+                    previous_version,
+                ));
+                continue;
+            }
+            for (version, index) in added {
+                result.push(PackageChange {
+                    package: String::from(name),
+                    previous_version: None,
+                    current_version: Some(version.clone()),
+                    change: Change::None,
+                    chain: self.dependency_chain(&current_tree, *index),
+                });
+            }
+            for (version, index) in removed {
+                result.push(PackageChange {
+                    package: String::from(name),
+                    previous_version: Some(version.clone()),
+                    current_version: None,
+                    change: Change::None,
+                    chain: previous.dependency_chain(&previous_tree, *index),
+                });
+            }
+        }
+
+        result
+    }
+
+    /// Crates with more than one version in the lock file, noting whether the change introduced,
+    /// extended or kept each duplicate, and crates whose duplicate was resolved.  A duplicate is
+    /// extended when it gains a version it lacked before.  Records the direct dependency, added or
+    /// changed in Cargo.toml, which pulls in a new version.
+    pub fn duplicate_changes(
+        &self,
+        current_manifest: &CargoTomlFile,
+        previous: &Self,
+        previous_manifest: &CargoTomlFile,
+    ) -> Vec<DuplicateChange> {
+        let mut result = Vec::new();
+
+        let previous_requirements = previous_manifest.dependency_requirements();
+        let changed_packages: BTreeSet<&str> = current_manifest
+            .dependency_requirements()
+            .iter()
+            .filter(|(name, requirement)| previous_requirements.get(*name) != Some(requirement))
+            .map(|(_, requirement)| requirement.package)
+            .collect();
+
+        let current_tree = self.dependency_tree(current_manifest);
+        let current_versions = self.package_versions(&current_tree);
+        let previous_versions =
+            previous.package_versions(&previous.dependency_tree(previous_manifest));
+        let version_list = |versions: &[(semver::Version, usize)]| {
+            versions
+                .iter()
+                .map(|(version, _)| version.clone())
+                .collect::<Vec<_>>()
+        };
+
+        for (name, versions) in &current_versions {
+            if versions.len() < 2 {
+                continue;
+            }
+            let previous_packages = previous_versions.get(name).map_or(&[][..], Vec::as_slice);
+            let mut added_versions = versions
+                .iter()
+                .filter(|(version, _)| !previous_packages.iter().any(|(val, _)| val == version))
+                .peekable();
+            let action = if previous_packages.len() < 2 {
+                DuplicateAction::Introduce
+            } else if added_versions.peek().is_some() {
+                DuplicateAction::Extend
+            } else {
+                DuplicateAction::Keep
+            };
+            let cause = added_versions.find_map(|(_, index)| {
+                let chain = self.dependency_chain(&current_tree, *index)?;
+                let direct_dependency = chain.first().map_or(*name, String::as_str);
+                changed_packages
+                    .contains(direct_dependency)
+                    .then(|| String::from(direct_dependency))
+            });
+
+            result.push(DuplicateChange {
+                package: String::from(*name),
+                action,
+                versions: version_list(versions),
+                cause,
+            });
+        }
+
+        for (name, versions) in &previous_versions {
+            let current_count = current_versions.get(name).map_or(0, Vec::len);
+            if versions.len() > 1 && current_count < 2 {
+                result.push(DuplicateChange {
+                    package: String::from(*name),
+                    action: DuplicateAction::Resolve,
+                    versions: version_list(versions),
+                    cause: None,
+                });
+            }
+        }
+
+        result
+    }
+
+    /// Lists packages whose checksum changed while their version stayed the same, and packages
+    /// whose source switched, for example from the crates.io registry to a git URL.  Both are
+    /// supply-chain signals, so reported at the highest severity.
+    pub fn supply_chain_changes(&self, previous: &Self) -> Vec<SupplyChainChange> {
+        let mut result = Vec::new();
+
+        for package in &self.packages {
+            let CargoLockPackage {
+                name,
+                version,
+                source,
+                checksum,
+                ..
+            } = package;
+            let previous_packages: Vec<&CargoLockPackage> = previous
+                .packages
+                .iter()
+                .filter(|val| &val.name == name)
+                .collect();
+            let Some(previous_package) = previous_packages
+                .iter()
+                .find(|val| &val.version == version)
+                .or(previous_packages.first())
+            else {
+                continue;
+            };
+
+            let change =
+                |field, previous_value: &Option<String>, current_value: &Option<String>| {
+                    SupplyChainChange {
+                        package: name.clone(),
+                        version: version.clone(),
+                        field,
+                        previous: previous_value.clone(),
+                        current: current_value.clone(),
+                    }
+                };
+            if !previous_packages.iter().any(|val| &val.source == source) {
+                result.push(change(
+                    SupplyChainField::Source,
+                    &previous_package.source,
+                    source,
+                ));
+            } else if previous_package.version == *version
+                && previous_package.source == *source
+                && previous_package.checksum.is_some()
+                && checksum.is_some()
+                && previous_package.checksum != *checksum
+            {
+                result.push(change(
+                    SupplyChainField::Checksum,
+                    &previous_package.checksum,
+                    checksum,
+                ));
+            }
+        }
+
+        result
+    }
+
+    fn fmt_requirement(requirement: Option<&str>) -> String {
+        match requirement.map(SemverVersion::new) {
+            Some(Ok(version)) => format!("{version}"),
+            Some(Err(_)) => String::from(requirement.unwrap_or_default()),
+            None => String::from("git"),
+        }
+    }
+
+    /// Direct dependencies, listed in both manifests, whose locked version changed, alongside any
+    /// change to their Cargo.toml requirement
+    pub fn locked_version_changes(
+        &self,
+        current_manifest: &CargoTomlFile,
+        previous: &Self,
+        previous_manifest: &CargoTomlFile,
+    ) -> Vec<LockedVersionChange> {
+        let previous_requirements = previous_manifest.dependency_requirements();
+
+        current_manifest
+            .dependency_requirements()
+            .into_iter()
+            .filter_map(|(name, current_requirement)| {
+                let previous_requirement = previous_requirements.get(name)?;
+                let current_locked = self
+                    .locked_version(current_requirement.package, current_requirement.requirement)?;
+                let previous_locked = previous.locked_version(
+                    previous_requirement.package,
+                    previous_requirement.requirement,
+                )?;
+
+                (current_locked != previous_locked).then(|| LockedVersionChange {
+                    package: String::from(current_requirement.package),
+                    previous_requirement: previous_requirement.requirement.map(String::from),
+                    current_requirement: current_requirement.requirement.map(String::from),
+                    previous_locked,
+                    current_locked,
+                })
+            })
+            .collect()
+    }
+
+    /// Warns when the manifest requirements changed, but the lock file did not
+    pub fn print_unchanged_lock_warning(
+        &self,
+        current_manifest: &CargoTomlFile,
+        previous: &Self,
+        previous_manifest: &CargoTomlFile,
+        style: Style,
+    ) -> String {
+        let mut result = String::new();
+
+        if current_manifest.dependency_requirements() != previous_manifest.dependency_requirements()
+            && self == previous
+        {
+            let _ = writeln!(
+                result,
+                "{} Cargo.toml dependencies changed, but Cargo.lock did not.  Run `cargo update` \
+                    or build the project to refresh the lock file.",
+                style.mark(Mark::Warning)
+            );
+        }
+
+        result
+    }
+
+    /// Dependencies whose requirement changed, as package name, new requirement and previous
+    /// requirement.  Git dependencies and requirements which cannot be parsed are left out.
+    /// Package and requirement for each dependency whose requirement changed between the two
+    /// manifests
+    fn changed_requirements<'a>(
+        current_manifest: &'a CargoTomlFile,
+        previous_manifest: &'a CargoTomlFile,
+    ) -> Vec<(&'a str, &'a str)> {
+        let previous_requirements = previous_manifest.dependency_requirements();
+
+        current_manifest
+            .dependency_requirements()
+            .into_iter()
+            .filter_map(|(name, current_requirement)| {
+                let previous_requirement = previous_requirements.get(name)?.requirement?;
+                let requirement = current_requirement.requirement?;
+                (requirement != previous_requirement && VersionReq::parse(requirement).is_ok())
+                    .then_some((current_requirement.package, requirement))
+            })
+            .collect()
+    }
+
+    /// Checks each changed requirement against the version in this lock file, which should be
+    /// the working tree one.  Where no locked version matches, the lock is out of date, and cargo
+    /// will re-resolve the dependency on the next build.
+    pub fn print_requirement_compatibility(
+        &self,
+        current_manifest: &CargoTomlFile,
+        previous_manifest: &CargoTomlFile,
+        style: Style,
+    ) -> String {
+        let mut result = String::new();
+
+        for (package_name, requirement) in
+            Self::changed_requirements(current_manifest, previous_manifest)
+        {
+            let requirement_display = Self::fmt_requirement(Some(requirement));
+            if let Some(locked) = self.locked_version(package_name, Some(requirement)) {
+                let _ = writeln!(
+                    result,
+                    "{} lock satisfies new requirement {package_name} {requirement_display}: \
+                        locked {locked}",
+                    style.mark(Mark::LockSatisfied)
+                );
+            } else if let Some(locked) = self.locked_version(package_name, None) {
+                let _ = writeln!(
+                    result,
+                    "{} lock no longer satisfies requirement {package_name} \
+                        {requirement_display}: locked {locked}, so cargo will re-resolve on the \
+                        next build",
+                    style.mark(Mark::LockStale)
+                );
+            }
+        }
+
+        result
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(serde::Serialize))]
+pub struct CargoLockPackage {
+    pub name: String,
+    pub version: String,
+    pub source: Option<String>,
+    pub checksum: Option<String>,
+    pub dependencies: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(test, derive(serde::Serialize))]
+pub struct CargoLockFile {
+    pub package: Option<Vec<CargoLockPackage>>,
+}
@@ -0,0 +1,695 @@
+use crate::domain::{CargoTomlFile, Style};
+
+use super::File;
+
+/// Text report lines for `changes`, each formatted by `fmt_line`
+fn print<T>(changes: &[T], fmt_line: impl Fn(&T) -> String) -> String {
+    changes
+        .iter()
+        .map(|change| fmt_line(change) + "\n")
+        .collect()
+}
+
+const EARLIER_CARGO_TOML: &str = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+getrandom = "0.3"
+getrandom2 = { package = "getrandom", version = "0.2" }
+log = "0.4.22"
+serde = { version = "1.0.200", features = ["derive"] }
+"#;
+
+const EARLIER_CARGO_LOCK: &str = r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 4
+
+[[package]]
+name = "getrandom"
+version = "0.2.15"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "c4567c8db10ae91089c99af84c68c38da3ec2f087c3f82960bcdbf3656b6f4d7"
+
+[[package]]
+name = "getrandom"
+version = "0.3.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "43a49c392881ce6d5c3b8cb70f98717b7c07aabbdff06687b9030dbfbe2725f8"
+
+[[package]]
+name = "log"
+version = "0.4.22"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "a7a70ba024b9dc04c27ea2f0c0548feb474ec5c54bba33a7f72f873a39d07b24"
+
+[[package]]
+name = "serde"
+version = "1.0.210"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "c8e3592472072e6e22e0a54d5904d9febf8508f65fb8552499a1abc7d1078c3a"
+
+[[package]]
+name = "some-example-crate"
+version = "0.1.0"
+dependencies = [
+ "getrandom 0.2.15",
+ "getrandom 0.3.1",
+ "log",
+ "serde",
+]
+"#;
+
+#[test]
+fn new_from_str_parses_valid_cargo_lock() {
+    // act
+    let outcome = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+
+    // assert
+    assert_eq!(outcome.packages.len(), 5);
+    assert_eq!(
+        outcome.packages[4].dependencies,
+        Some(vec![
+            String::from("getrandom 0.2.15"),
+            String::from("getrandom 0.3.1"),
+            String::from("log"),
+            String::from("serde"),
+        ])
+    );
+}
+
+#[test]
+fn new_handles_missing_cargo_lock() {
+    // arrange
+    let temp_dir = assert_fs::TempDir::new().unwrap();
+    let temporary_cargo_lock_path = temp_dir.join("Cargo.lock");
+
+    // act
+    let outcome = File::new(temporary_cargo_lock_path.to_str().unwrap()).unwrap_err();
+
+    // assert
+    assert_eq!(
+        format!("{outcome}"),
+        format!(
+            "Error opening Cargo.lock file: `{}`",
+            temporary_cargo_lock_path.display()
+        )
+    );
+}
+
+#[test]
+fn locked_version_picks_version_matching_requirement() {
+    // arrange
+    let cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+
+    // act
+    let zero_two = cargo_lock.locked_version("getrandom", Some("0.2"));
+    let zero_three = cargo_lock.locked_version("getrandom", Some("0.3"));
+    let unconstrained = cargo_lock.locked_version("getrandom", None);
+    let missing = cargo_lock.locked_version("ahash", Some("0.8"));
+
+    // assert
+    assert_eq!(zero_two, Some(semver::Version::new(0, 2, 15)));
+    assert_eq!(zero_three, Some(semver::Version::new(0, 3, 1)));
+    assert_eq!(unconstrained, Some(semver::Version::new(0, 3, 1)));
+    assert_eq!(missing, None);
+}
+
+#[test]
+fn locked_version_changes_reports_direct_dependency_changes() {
+    // arrange
+    let updated_cargo_toml = CargoTomlFile::new_from_str(
+        &EARLIER_CARGO_TOML.replace(r#"version = "1.0.200""#, r#"version = "1.0.215""#),
+    )
+    .unwrap();
+    let earlier_cargo_toml = CargoTomlFile::new_from_str(EARLIER_CARGO_TOML).unwrap();
+    let updated_cargo_lock = File::new_from_str(
+        &EARLIER_CARGO_LOCK
+            .replace(r#"version = "1.0.210""#, r#"version = "1.0.217""#)
+            .replace(r#"version = "0.2.15""#, r#"version = "0.2.16""#),
+    )
+    .unwrap();
+    let earlier_cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+
+    // act
+    let output = print(
+        &updated_cargo_lock.locked_version_changes(
+            &updated_cargo_toml,
+            &earlier_cargo_lock,
+            &earlier_cargo_toml,
+        ),
+        |change| change.fmt_line(Style::Emoji),
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "🔒 getrandom requirement 0.2, locked 0.2.15 → 0.2.16\n\
+                🔒 serde requirement 1.0.200 → 1.0.215, locked 1.0.210 → 1.0.217\n"
+        )
+    );
+}
+
+#[test]
+fn print_unchanged_lock_warning_warns_when_lock_is_unchanged() {
+    // arrange
+    let updated_cargo_toml = CargoTomlFile::new_from_str(
+        &EARLIER_CARGO_TOML.replace(r#"version = "1.0.200""#, r#"version = "1.0.215""#),
+    )
+    .unwrap();
+    let earlier_cargo_toml = CargoTomlFile::new_from_str(EARLIER_CARGO_TOML).unwrap();
+    let cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+
+    // act
+    let output = cargo_lock.print_unchanged_lock_warning(
+        &updated_cargo_toml,
+        &cargo_lock,
+        &earlier_cargo_toml,
+        Style::Emoji,
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "⚠️ Cargo.toml dependencies changed, but Cargo.lock did not.  Run `cargo update` or \
+                build the project to refresh the lock file.\n"
+        )
+    );
+}
+
+#[test]
+fn locked_version_changes_are_empty_when_nothing_changed() {
+    // arrange
+    let cargo_toml = CargoTomlFile::new_from_str(EARLIER_CARGO_TOML).unwrap();
+    let cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+
+    // act
+    let changes = cargo_lock.locked_version_changes(&cargo_toml, &cargo_lock, &cargo_toml);
+    let output = cargo_lock.print_unchanged_lock_warning(
+        &cargo_toml,
+        &cargo_lock,
+        &cargo_toml,
+        Style::Emoji,
+    );
+
+    // assert
+    assert_eq!(changes, Vec::new());
+    assert_eq!(output, String::new());
+}
+
+const TRANSITIVE_CARGO_TOML: &str = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+reqwest = "0.12"
+"#;
+
+const TRANSITIVE_CARGO_LOCK: &str = r#"version = 4
+
+[[package]]
+name = "h2"
+version = "0.4.6"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "hyper"
+version = "1.5.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "h2",
+ "want",
+]
+
+[[package]]
+name = "reqwest"
+version = "0.12.9"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "hyper",
+]
+
+[[package]]
+name = "some-example-crate"
+version = "0.1.0"
+dependencies = [
+ "reqwest",
+]
+
+[[package]]
+name = "want"
+version = "0.3.1"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+
+#[test]
+fn package_changes_reports_transitive_changes_with_chains() {
+    // arrange
+    let updated_cargo_lock = File::new_from_str(
+        &TRANSITIVE_CARGO_LOCK
+            .replace(r#"version = "0.4.6""#, r#"version = "0.4.7""#)
+            .replace(
+                "dependencies = [\n \"h2\",\n \"want\",\n]",
+                "dependencies = [\n \"atomic-waker\",\n \"h2\",\n]",
+            )
+            .replace(
+                "[[package]]\nname = \"want\"",
+                "[[package]]\nname = \"atomic-waker\"",
+            )
+            .replace(r#"version = "0.12.9""#, r#"version = "0.12.12""#),
+    )
+    .unwrap();
+    let earlier_cargo_lock = File::new_from_str(TRANSITIVE_CARGO_LOCK).unwrap();
+    let cargo_toml = CargoTomlFile::new_from_str(TRANSITIVE_CARGO_TOML).unwrap();
+    let unlisted_cargo_toml = CargoTomlFile::new_from_str(
+        "[package]\nname = \"some-example-crate\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+
+    // act
+    let output = print(
+        &updated_cargo_lock.package_changes(&cargo_toml, &earlier_cargo_lock, &cargo_toml),
+        |change| change.fmt_line(Style::Emoji),
+    );
+    let unlisted_output = print(
+        &updated_cargo_lock.package_changes(
+            &unlisted_cargo_toml,
+            &earlier_cargo_lock,
+            &unlisted_cargo_toml,
+        ),
+        |change| change.fmt_line(Style::Plain),
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "✨ add atomic-waker (🔒 Cargo.lock) 0.3.1 (via reqwest → hyper)\n\
+                📦 bump h2 (🔒 Cargo.lock) from 0.4.6 to 0.4.7 (via reqwest → hyper)\n\
+                🗑\u{fe0f} remove want (🔒 Cargo.lock) 0.3.1 (via reqwest → hyper)\n"
+        )
+    );
+    assert_eq!(
+        unlisted_output,
+        String::from(
+            "ADD atomic-waker (Cargo.lock) 0.3.1 (via reqwest -> hyper)\n\
+                MINOR bump h2 (Cargo.lock) from 0.4.6 to 0.4.7 (via reqwest -> hyper)\n\
+                MINOR bump reqwest (Cargo.lock) from 0.12.9 to 0.12.12 (direct)\n\
+                REMOVE want (Cargo.lock) 0.3.1 (via reqwest -> hyper)\n"
+        )
+    );
+}
+
+#[test]
+fn package_changes_starts_chains_at_workspace_members() {
+    // arrange
+    let path_dependency_lock = TRANSITIVE_CARGO_LOCK
+        .replace(" \"reqwest\",\n]", " \"local-client\",\n]")
+        .replace(
+            "[[package]]\nname = \"reqwest\"",
+            "[[package]]\nname = \"local-client\"\nversion = \"0.1.0\"\ndependencies = [\n \"reqwest\",\n]\n\n\
+                [[package]]\nname = \"reqwest\"",
+        );
+    let earlier_cargo_lock = File::new_from_str(&path_dependency_lock).unwrap();
+    let updated_cargo_lock = File::new_from_str(
+        &path_dependency_lock.replace(r#"version = "0.4.6""#, r#"version = "0.4.7""#),
+    )
+    .unwrap();
+    let cargo_toml = CargoTomlFile::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+
+[dependencies]
+local-client = { path = "../local-client", version = "0.1.0" }
+"#,
+    )
+    .unwrap();
+    let workspace_cargo_toml = CargoTomlFile::new_from_str(
+        r#"[workspace]
+members = ["app", "crates/local-*"]
+"#,
+    )
+    .unwrap();
+
+    // act
+    let output = print(
+        &updated_cargo_lock.package_changes(&cargo_toml, &earlier_cargo_lock, &cargo_toml),
+        |change| change.fmt_line(Style::Emoji),
+    );
+    let workspace_output = print(
+        &updated_cargo_lock.package_changes(
+            &workspace_cargo_toml,
+            &earlier_cargo_lock,
+            &workspace_cargo_toml,
+        ),
+        |change| change.fmt_line(Style::Emoji),
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "📦 bump h2 (🔒 Cargo.lock) from 0.4.6 to 0.4.7 (via local-client → reqwest → hyper)\n"
+        )
+    );
+    assert_eq!(
+        workspace_output,
+        String::from("📦 bump h2 (🔒 Cargo.lock) from 0.4.6 to 0.4.7 (via reqwest → hyper)\n")
+    );
+}
+
+#[test]
+fn package_changes_handles_build_metadata() {
+    // arrange
+    let earlier_cargo_lock = File::new_from_str(&TRANSITIVE_CARGO_LOCK.replace(
+        "[[package]]\nname = \"want\"\nversion = \"0.3.1\"",
+        "[[package]]\nname = \"want\"\nversion = \"0.3.1+1.9.2\"",
+    ))
+    .unwrap();
+    let updated_cargo_lock = File::new_from_str(&TRANSITIVE_CARGO_LOCK.replace(
+        "[[package]]\nname = \"want\"\nversion = \"0.3.1\"",
+        "[[package]]\nname = \"want\"\nversion = \"0.3.2+1.9.3\"",
+    ))
+    .unwrap();
+    let cargo_toml = CargoTomlFile::new_from_str(TRANSITIVE_CARGO_TOML).unwrap();
+
+    // act
+    let output = print(
+        &updated_cargo_lock.package_changes(&cargo_toml, &earlier_cargo_lock, &cargo_toml),
+        |change| change.fmt_line(Style::Emoji),
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "📦 bump want (🔒 Cargo.lock) from 0.3.1+1.9.2 to 0.3.2+1.9.3 (via reqwest → hyper)\n"
+        )
+    );
+}
+
+#[test]
+fn package_changes_distinguishes_multiple_versions() {
+    // arrange
+    let updated_cargo_lock = File::new_from_str(&EARLIER_CARGO_LOCK.replace(
+        "[[package]]\nname = \"log\"\nversion = \"0.4.22\"",
+        "[[package]]\nname = \"getrandom\"\nversion = \"0.1.16\"",
+    ))
+    .unwrap();
+    let earlier_cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+    let cargo_toml = CargoTomlFile::new_from_str(EARLIER_CARGO_TOML).unwrap();
+
+    // act
+    let output = print(
+        &updated_cargo_lock.package_changes(&cargo_toml, &earlier_cargo_lock, &cargo_toml),
+        |change| change.fmt_line(Style::Emoji),
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "✨ add getrandom (🔒 Cargo.lock) 0.1.16\n\
+                🗑\u{fe0f} remove log (🔒 Cargo.lock) 0.4.22 (direct)\n"
+        )
+    );
+}
+
+#[test]
+fn duplicate_changes_reports_duplicate_status() {
+    // arrange
+    let cargo_toml = CargoTomlFile::new_from_str(EARLIER_CARGO_TOML).unwrap();
+    let updated_cargo_lock = File::new_from_str(&format!(
+        "{EARLIER_CARGO_LOCK}
+[[package]]
+name = \"log\"
+version = \"0.3.9\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+"
+    ))
+    .unwrap();
+    let earlier_cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+
+    // act
+    let output = print(
+        &updated_cargo_lock.duplicate_changes(&cargo_toml, &earlier_cargo_lock, &cargo_toml),
+        |change| change.fmt_line(Style::Emoji),
+    );
+    let reverse_output = print(
+        &earlier_cargo_lock.duplicate_changes(&cargo_toml, &updated_cargo_lock, &cargo_toml),
+        |change| change.fmt_line(Style::Emoji),
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "👯 keep duplicate getrandom (🔒 Cargo.lock) 0.2.15, 0.3.1\n\
+                👯 introduce duplicate log (🔒 Cargo.lock) 0.3.9, 0.4.22\n"
+        )
+    );
+    assert_eq!(
+        reverse_output,
+        String::from(
+            "👯 keep duplicate getrandom (🔒 Cargo.lock) 0.2.15, 0.3.1\n\
+                🧹 resolve duplicate log (🔒 Cargo.lock) 0.3.9, 0.4.22\n"
+        )
+    );
+}
+
+#[test]
+fn duplicate_changes_highlights_duplicates_from_manifest_changes() {
+    // arrange
+    let earlier_cargo_toml = CargoTomlFile::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+h2 = "0.3"
+reqwest = "0.12.9"
+"#,
+    )
+    .unwrap();
+    let updated_cargo_toml = CargoTomlFile::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+h2 = "0.3"
+reqwest = "0.12.12"
+"#,
+    )
+    .unwrap();
+    let earlier_cargo_lock = File::new_from_str(
+        &TRANSITIVE_CARGO_LOCK
+            .replace(r#"version = "0.4.6""#, r#"version = "0.3.26""#)
+            .replace(" \"reqwest\",\n", " \"h2\",\n \"reqwest\",\n"),
+    )
+    .unwrap();
+    let updated_cargo_lock = File::new_from_str(&format!(
+        "{}
+[[package]]
+name = \"h2\"
+version = \"0.3.26\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+",
+        TRANSITIVE_CARGO_LOCK
+            .replace(r#"version = "0.12.9""#, r#"version = "0.12.12""#)
+            .replace(" \"reqwest\",\n", " \"h2 0.3.26\",\n \"reqwest\",\n")
+            .replace(" \"h2\",\n \"want\"", " \"h2 0.4.6\",\n \"want\"")
+    ))
+    .unwrap();
+
+    // act
+    let output = print(
+        &updated_cargo_lock.duplicate_changes(
+            &updated_cargo_toml,
+            &earlier_cargo_lock,
+            &earlier_cargo_toml,
+        ),
+        |change| change.fmt_line(Style::Emoji),
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "🚨 introduce duplicate h2 (🔒 Cargo.lock) 0.3.26, 0.4.6 (from Cargo.toml change to \
+                reqwest)\n"
+        )
+    );
+}
+
+#[test]
+fn duplicate_changes_reports_versions_added_to_duplicates() {
+    // arrange
+    let earlier_cargo_toml = CargoTomlFile::new_from_str(EARLIER_CARGO_TOML).unwrap();
+    let updated_cargo_toml = CargoTomlFile::new_from_str(
+        &EARLIER_CARGO_TOML.replace(r#"log = "0.4.22""#, r#"log = "~0.4.22""#),
+    )
+    .unwrap();
+    let earlier_cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+    let updated_cargo_lock = File::new_from_str(&format!(
+        "{}
+[[package]]
+name = \"getrandom\"
+version = \"0.1.16\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+",
+        EARLIER_CARGO_LOCK.replace(
+            "checksum = \"a7a70ba024b9dc04c27ea2f0c0548feb474ec5c54bba33a7f72f873a39d07b24\"\n",
+            "checksum = \"a7a70ba024b9dc04c27ea2f0c0548feb474ec5c54bba33a7f72f873a39d07b24\"\n\
+                dependencies = [\n \"getrandom 0.1.16\",\n]\n"
+        )
+    ))
+    .unwrap();
+
+    // act
+    let output = print(
+        &updated_cargo_lock.duplicate_changes(
+            &updated_cargo_toml,
+            &earlier_cargo_lock,
+            &earlier_cargo_toml,
+        ),
+        |change| change.fmt_line(Style::Emoji),
+    );
+    let unchanged_manifest_output = print(
+        &updated_cargo_lock.duplicate_changes(
+            &earlier_cargo_toml,
+            &earlier_cargo_lock,
+            &earlier_cargo_toml,
+        ),
+        |change| change.fmt_line(Style::Plain),
+    );
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "🚨 extend duplicate getrandom (🔒 Cargo.lock) 0.1.16, 0.2.15, 0.3.1 (from Cargo.toml \
+                change to log)\n"
+        )
+    );
+    assert_eq!(
+        unchanged_manifest_output,
+        String::from("DUPLICATE extend duplicate getrandom (Cargo.lock) 0.1.16, 0.2.15, 0.3.1\n")
+    );
+}
+
+#[test]
+fn supply_chain_changes_flags_source_and_checksum_changes() {
+    // arrange
+    let updated_cargo_lock = File::new_from_str(
+        &EARLIER_CARGO_LOCK
+            .replace(
+                "a7a70ba024b9dc04c27ea2f0c0548feb474ec5c54bba33a7f72f873a39d07b24",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            )
+            .replace(
+                "version = \"1.0.210\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\nchecksum = \"c8e3592472072e6e22e0a54d5904d9febf8508f65fb8552499a1abc7d1078c3a\"",
+                "version = \"1.0.210\"\nsource = \"git+https://github.com/example/serde?rev=abc123#abc123\"",
+            ),
+    )
+    .unwrap();
+    let earlier_cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+
+    // act
+    let output: Vec<String> = updated_cargo_lock
+        .supply_chain_changes(&earlier_cargo_lock)
+        .iter()
+        .map(|change| change.fmt_line(Style::Emoji))
+        .collect();
+
+    // assert
+    assert_eq!(
+        output,
+        vec![
+            String::from(
+                "🛑 change checksum log (🔒 Cargo.lock) 0.4.22 from \
+                    a7a70ba024b9dc04c27ea2f0c0548feb474ec5c54bba33a7f72f873a39d07b24 to \
+                    0000000000000000000000000000000000000000000000000000000000000000"
+            ),
+            String::from(
+                "🛑 change source serde (🔒 Cargo.lock) 1.0.210 from \
+                    registry+https://github.com/rust-lang/crates.io-index to \
+                    git+https://github.com/example/serde?rev=abc123#abc123"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn supply_chain_changes_ignores_version_bumps() {
+    // arrange
+    let updated_cargo_lock = File::new_from_str(
+        &EARLIER_CARGO_LOCK
+            .replace(r#"version = "0.4.22""#, r#"version = "0.4.25""#)
+            .replace(
+                "a7a70ba024b9dc04c27ea2f0c0548feb474ec5c54bba33a7f72f873a39d07b24",
+                "13dc2df351e3202783a1fe0d44375f7295ffb4049267b0f3018346dc122a1d94",
+            ),
+    )
+    .unwrap();
+    let earlier_cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+
+    // act
+    let output = updated_cargo_lock.supply_chain_changes(&earlier_cargo_lock);
+
+    // assert
+    assert_eq!(output, Vec::new());
+}
+
+#[test]
+fn print_requirement_compatibility_checks_changed_requirements_against_lock() {
+    // arrange
+    let updated_cargo_toml = CargoTomlFile::new_from_str(
+        &EARLIER_CARGO_TOML
+            .replace(r#"log = "0.4.22""#, r#"log = "0.4""#)
+            .replace(r#"version = "1.0.200""#, r#"version = "1.0.215""#),
+    )
+    .unwrap();
+    let earlier_cargo_toml = CargoTomlFile::new_from_str(EARLIER_CARGO_TOML).unwrap();
+    let stale_cargo_lock = File::new_from_str(EARLIER_CARGO_LOCK).unwrap();
+    let updated_cargo_lock = File::new_from_str(
+        &EARLIER_CARGO_LOCK.replace(r#"version = "1.0.210""#, r#"version = "1.0.217""#),
+    )
+    .unwrap();
+
+    // act
+    let stale_output = stale_cargo_lock.print_requirement_compatibility(
+        &updated_cargo_toml,
+        &earlier_cargo_toml,
+        Style::Emoji,
+    );
+    let updated_output = updated_cargo_lock.print_requirement_compatibility(
+        &updated_cargo_toml,
+        &earlier_cargo_toml,
+        Style::Emoji,
+    );
+
+    // assert
+    assert_eq!(
+        stale_output,
+        String::from(
+            "🔐 lock satisfies new requirement log 0.4: locked 0.4.22\n\
+                🔓 lock no longer satisfies requirement serde 1.0.215: locked 1.0.210, so cargo \
+                will re-resolve on the next build\n"
+        )
+    );
+    assert_eq!(
+        updated_output,
+        String::from(
+            "🔐 lock satisfies new requirement log 0.4: locked 0.4.22\n\
+                🔐 lock satisfies new requirement serde 1.0.215: locked 1.0.217\n"
+        )
+    );
+}
@@ -2,17 +2,49 @@
 mod tests;
 
 use core::str;
-use std::{
-    cmp::Ordering,
-    collections::{BTreeMap, BTreeSet},
-    fmt::Write as _,
-};
+use std::{collections::BTreeMap, fmt, fs};
 
 use anyhow::{anyhow, Context};
-use config::Config;
-use serde::Deserialize;
+use serde::{de::IgnoredAny, Deserialize};
+use toml::Spanned;
+
+use super::{
+    change_set::{DependencyChange, DependencySource, Kind, Section},
+    diagnostic::{Code, Diagnostic},
+    semver::Change,
+    style::{Mark, Style},
+    BuildTarget, BuildTargets, ChangeSet, SemverVersion,
+};
+
+/// Location of a dependency entry in Cargo.toml, from the start of its key to the end of its
+/// value.  `start` and `end` are byte offsets, and `line` and `column` are 1-based.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    fn new(source: &str, start: usize, end: usize) -> Self {
+        let before = &source[..start];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
 
-use super::SemverVersion;
+        Self {
+            start,
+            end,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
 
 #[derive(Debug)]
 pub struct File {
@@ -20,322 +52,578 @@ pub struct File {
     build_dependencies: Option<BTreeMap<String, CargoDependencyValue>>,
     dev_dependencies: Option<BTreeMap<String, CargoDependencyValue>>,
     workspace_dependencies: Option<BTreeMap<String, CargoDependencyValue>>,
+    targets: BuildTargets,
+
+    /// `[package]` name, absent for a virtual workspace manifest
+    package_name: Option<String>,
+
+    /// `[workspace] members` entries, which are paths and may include a `*` wildcard
+    workspace_members: Vec<String>,
+
+    /// Location of each dependency entry, for annotations and error messages
+    dependency_spans: BTreeMap<(Section, String), Span>,
 }
 
 impl File {
     pub fn new(path: &str) -> anyhow::Result<Self> {
-        let current_cargo = Config::builder()
-            .add_source(config::File::with_name(path))
-            .build()
+        let cargo_toml_str = fs::read_to_string(path)
             .with_context(|| format!("Error opening Cargo.toml file: `{path}`"))?;
-        let CargoFile {
-            dependencies,
-            build_dependencies,
-            dev_dependencies,
-            workspace,
-        } = current_cargo
-            .try_deserialize::<CargoFile>()
+        let result = Self::parse(&cargo_toml_str)
+            .map_err(|diagnostic| diagnostic.with_origin(path, Some("working tree")))
             .with_context(|| format!("Error parsing `{path}`"))?;
+        log::trace!("Cargo dependencies: {:?}", result.dependencies);
+        log::trace!("Cargo build-dependencies: {:?}", result.build_dependencies);
+        log::trace!("Cargo dev-dependencies: {:?}", result.dev_dependencies);
+        log::trace!(
+            "Cargo workspace-dependencies: {:?}",
+            result.workspace_dependencies
+        );
+        log::trace!("Cargo targets: {:?}", result.targets);
 
-        let workspace_dependencies = if let Some(workspace_val) = workspace {
-            workspace_val.dependencies
-        } else {
-            None
-        };
-        log::trace!("Cargo dependencies: {dependencies:?}");
-        log::trace!("Cargo build-dependencies: {build_dependencies:?}");
-        log::trace!("Cargo dev-dependencies: {dev_dependencies:?}");
-        log::trace!("Cargo workspace-dependencies: {workspace_dependencies:?}");
-
-        Ok(Self {
-            dependencies,
-            build_dependencies,
-            dev_dependencies,
-            workspace_dependencies,
-        })
+        Ok(result)
     }
 
-    pub fn new_from_buffer(buffer: &[u8]) -> anyhow::Result<Self> {
+    /// `revision` names the Git revision the buffer was read from, for example `HEAD`, in error
+    /// messages
+    pub fn new_from_buffer(buffer: &[u8], revision: &str) -> anyhow::Result<Self> {
         let cargo_toml_str = str::from_utf8(buffer).context("Creating `CargoFile` from buffer")?;
 
-        Self::new_from_str(cargo_toml_str)
+        Ok(Self::parse(cargo_toml_str)
+            .map_err(|diagnostic| diagnostic.with_origin("Cargo.toml", Some(revision)))?)
     }
 
+    #[cfg(test)]
     pub fn new_from_str(toml_str: &str) -> anyhow::Result<Self> {
-        let CargoFile {
+        Self::parse(toml_str).map_err(|diagnostic| anyhow::Error::new(*diagnostic))
+    }
+
+    /// Errors are diagnostics, locating the problem in `toml_str`
+    fn parse(toml_str: &str) -> Result<Self, Box<Diagnostic>> {
+        let table: toml::Table = toml::from_str(toml_str)
+            .map_err(|error| Self::toml_diagnostic(Code::InvalidToml, &error, toml_str))?;
+        let dependency_spans = Self::get_dependency_spans(toml_str)
+            .map_err(|error| Self::toml_diagnostic(Code::UnexpectedValue, &error, toml_str))?;
+        let cargo_file: CargoFile = toml::from_str(toml_str).map_err(|error| {
+            Self::dependency_diagnostic(&table, &dependency_spans, toml_str)
+                .unwrap_or_else(|| Self::toml_diagnostic(Code::UnexpectedValue, &error, toml_str))
+        })?;
+        let mut result = Self::from(cargo_file);
+        result.dependency_spans = dependency_spans;
+        log::trace!("Cargo: {:?}", result.dependencies);
+
+        Ok(result)
+    }
+
+    fn toml_diagnostic(code: Code, error: &toml::de::Error, toml_str: &str) -> Diagnostic {
+        let span = error.span().unwrap_or_default();
+
+        Diagnostic::new(
+            code,
+            error.message().trim_end(),
+            toml_str,
+            Span::new(toml_str, span.start, span.end),
+        )
+    }
+
+    /// Explains why the first unreadable dependency entry in `table` is not supported, or returns
+    /// `None` if every entry can be read
+    fn dependency_diagnostic(
+        table: &toml::Table,
+        spans: &BTreeMap<(Section, String), Span>,
+        toml_str: &str,
+    ) -> Option<Diagnostic> {
+        for section in Section::ALL {
+            let Some(entries) = section
+                .to_string()
+                .split('.')
+                .try_fold(table, |parent, key| parent.get(key)?.as_table())
+            else {
+                continue;
+            };
+            for (name, value) in entries {
+                if value.clone().try_into::<CargoDependencyValue>().is_ok() {
+                    continue;
+                }
+                let span = spans
+                    .get(&(section, name.clone()))
+                    .copied()
+                    .unwrap_or_else(|| Span::new(toml_str, 0, 0));
+                let detail = value.as_table();
+                let has_key = |key: &str| detail.is_some_and(|fields| fields.contains_key(key));
+                let inherited = detail
+                    .and_then(|fields| fields.get("workspace"))
+                    .and_then(toml::Value::as_bool)
+                    == Some(true);
+
+                let diagnostic = if inherited {
+                    Diagnostic::new(
+                        Code::InheritedDependency,
+                        &format!("inherited dependency `{name}` in `[{section}]`"),
+                        toml_str,
+                        span,
+                    )
+                    .with_hint("inherited `workspace = true` entries not supported")
+                } else if has_key("path") && !has_key("version") {
+                    Diagnostic::new(
+                        Code::PathDependency,
+                        &format!("path dependency `{name}` in `[{section}]` has no version"),
+                        toml_str,
+                        span,
+                    )
+                    .with_hint("path dependencies without a `version` requirement not supported")
+                } else {
+                    Diagnostic::new(
+                        Code::UnsupportedDependency,
+                        &format!("unsupported dependency `{name}` in `[{section}]`"),
+                        toml_str,
+                        span,
+                    )
+                    .with_hint("expected a version requirement, or a table with `version` or `git`")
+                };
+
+                return Some(diagnostic);
+            }
+        }
+
+        None
+    }
+
+    /// Location of each dependency entry in `toml_str`.  Keys are the dependency names, so
+    /// `[dependencies.serde]` tables start at the name in the table header.
+    fn get_dependency_spans(
+        toml_str: &str,
+    ) -> Result<BTreeMap<(Section, String), Span>, toml::de::Error> {
+        let SpannedCargoFile {
             dependencies,
             build_dependencies,
             dev_dependencies,
             workspace,
-        } = toml::from_str(toml_str).context("Creating `CargoFile` from str")?;
-        log::trace!("Cargo: {dependencies:?}");
+        } = toml::from_str(toml_str)?;
+        let sections = [
+            (Section::Dependencies, dependencies),
+            (Section::DevDependencies, dev_dependencies),
+            (Section::BuildDependencies, build_dependencies),
+            (
+                Section::WorkspaceDependencies,
+                workspace.and_then(|value| value.dependencies),
+            ),
+        ];
+
+        let mut result = BTreeMap::new();
+        for (section, keys) in sections {
+            for (key, value) in keys.into_iter().flatten() {
+                // `[dependencies.serde]` table values are not written after their key, so the span
+                // covers the key alone
+                let start = key.span().start;
+                let end = if value.span().start >= key.span().end {
+                    value.span().end
+                } else {
+                    key.span().end
+                };
+                result.insert((section, key.into_inner()), Span::new(toml_str, start, end));
+            }
+        }
 
-        let workspace_dependencies = if let Some(workspace_val) = workspace {
-            workspace_val.dependencies
-        } else {
-            None
-        };
+        Ok(result)
+    }
 
-        Ok(Self {
-            dependencies,
-            build_dependencies,
-            dev_dependencies,
-            workspace_dependencies,
-        })
+    /// Location of the dependency entry with key `name` in `section`
+    pub fn dependency_span(&self, section: Section, name: &str) -> Option<Span> {
+        self.dependency_spans
+            .get(&(section, String::from(name)))
+            .copied()
+    }
+
+    /// Whether `package` is a workspace member: the package this manifest describes, or one listed
+    /// in `[workspace] members`.  Members are listed by path, so are matched on the final path
+    /// component, which Cargo names the package after by default.
+    pub fn is_workspace_member(&self, package: &str) -> bool {
+        self.package_name.as_deref() == Some(package)
+            || self.workspace_members.iter().any(|member| {
+                let directory = member
+                    .trim_end_matches('/')
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or_default();
+                match directory.split_once('*') {
+                    Some((prefix, suffix)) => {
+                        package.starts_with(prefix) && package[prefix.len()..].ends_with(suffix)
+                    }
+                    None => directory == package,
+                }
+            })
+    }
+
+    /// Requirements for every direct dependency, keyed by the dependency key used in Cargo.toml.
+    /// Where the same key appears in several sections, the first of `[dependencies]`,
+    /// `[dev-dependencies]`, `[build-dependencies]` and `[workspace.dependencies]` wins.
+    pub fn dependency_requirements(&self) -> BTreeMap<&str, DependencyRequirement<'_>> {
+        let mut result = BTreeMap::new();
+        for dependencies in [
+            &self.dependencies,
+            &self.dev_dependencies,
+            &self.build_dependencies,
+            &self.workspace_dependencies,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for (name, value) in dependencies {
+                result
+                    .entry(name.as_str())
+                    .or_insert_with(|| DependencyRequirement::new(name, value));
+            }
+        }
+
+        result
     }
 
-    fn get_version(value: &CargoDependencyValue) -> anyhow::Result<SemverVersion> {
+    /// Parsed requirement, or `None` for git dependencies, which have no requirement
+    fn get_version(value: &CargoDependencyValue) -> anyhow::Result<Option<SemverVersion>> {
         match value {
-            CargoDependencyValue::Simple(version) => SemverVersion::new(version).map_err(|error| {
-                anyhow!(
-                    "Unexpected semver {version} found while computing dependency changes: \
+            CargoDependencyValue::Simple(version) => {
+                SemverVersion::new(version).map(Some).map_err(|error| {
+                    anyhow!(
+                        "Unexpected semver {version} found while computing dependency changes: \
                             {error}",
-                )
-            }),
+                    )
+                })
+            }
             CargoDependencyValue::Detailed(DetailedCargoDependency { version, .. }) => {
-                SemverVersion::new(version).map_err(|_| {
+                SemverVersion::new(version).map(Some).map_err(|_| {
                     anyhow!(
                         "Unexpected semver version `{version}` found while computing \
                             dependency changes"
                     )
                 })
             }
-            CargoDependencyValue::Git(GitCargoDependency { git, .. }) => {
-                log::warn!(
-                    "Git dependency `{git}` found, but version change detection for git \
-                        dependencies is not currently supported"
-                );
-                SemverVersion::new("0").map_err(|_| unreachable!("Version 0 should be valid"))
-            }
+            CargoDependencyValue::Git(_) => Ok(None),
         }
     }
 
-    fn get_changes_from_current_dependencies(
-        current_dependencies: &BTreeMap<String, CargoDependencyValue>,
-        previous_dependencies: &BTreeMap<String, CargoDependencyValue>,
-        label: Option<&str>,
-        previous_keys: &mut BTreeSet<String>,
-        result: &mut String,
-    ) -> anyhow::Result<()> {
-        for (name, current_value) in current_dependencies {
-            let current_version = Self::get_version(current_value)?;
-            let package_name = match current_value {
-                CargoDependencyValue::Simple(_) => name,
-                CargoDependencyValue::Git(GitCargoDependency { package, .. })
-                | CargoDependencyValue::Detailed(DetailedCargoDependency { package, .. }) => {
-                    if let Some(package_value) = package {
-                        package_value
-                    } else {
-                        name
-                    }
-                }
-            };
-            if let Some(previous_value) = previous_dependencies.get(name) {
-                // Handle dependencies in previous and current (filtering for ones with changed
-                // versions)
-                let previous_version = Self::get_version(previous_value)?;
-
-                // Housekeeping to make previous keys into a list of only crates removed in the
-                // current Cargo.toml
-                previous_keys.remove(name);
-
-                let change_type = current_version.change_type(&previous_version);
-                match current_version.partial_cmp(&previous_version) {
-                    Some(Ordering::Greater) => {
-                        if let Some(label_value) = label {
-                            let _ =
-                                writeln!(result,
-                                "{change_type} bump {package_name} {label_value} from {previous_version} \
-                                    to {current_version}",
-                            );
-                        } else {
-                            let _ = writeln!(
-                                result,
-                                "{change_type} bump {package_name} from {previous_version} to \
-                                    {current_version}",
-                            );
-                        }
-                    }
-                    Some(Ordering::Equal) => {}
-                    Some(Ordering::Less) => {
-                        if let Some(label_value) = label {
-                            let _ = writeln!(result,
-                            "{change_type} drop {package_name} {label_value} from {previous_version} to \
-                                {current_version}"
-                        );
-                        } else {
-                            let _ = writeln!(
-                                result,
-                                "{change_type} drop {package_name} from {previous_version} to \
-                                {current_version}"
-                            );
-                        }
-                    }
-                    None => {
-                        if let Some(label_value) = label {
-                            let _ = writeln!(result,
-                                "{change_type} change {package_name} {label_value} from {previous_version} \
-                                to {current_version}\n"
-                            );
-                        } else {
-                            let _ = writeln!(
-                                result,
-                                "{change_type} change {package_name} from {previous_version} to \
-                                {current_version}"
-                            );
-                        }
-                    }
-                }
-            } else {
-                // Handle added dependencies
-                if let Some(label_value) = label {
-                    let _ = writeln!(
-                        result,
-                        "✨ add {package_name} {label_value} {current_version}"
-                    );
-                } else {
-                    let _ = writeln!(result, "✨ add {package_name} {current_version}");
-                }
-            }
+    /// Record for the dependency, if it was added, or its source or requirement changed.
+    /// Returns an error if either requirement cannot be parsed or compared.
+    fn get_change_from_current_dependency(
+        section: Section,
+        name: &str,
+        current_value: &CargoDependencyValue,
+        previous_value: Option<&CargoDependencyValue>,
+        (current_span, previous_span): (Option<Span>, Option<Span>),
+    ) -> anyhow::Result<Option<DependencyChange>> {
+        let current = DependencyRequirement::new(name, current_value);
+        let current_version = Self::get_version(current_value)?;
+        let Some(previous_value) = previous_value else {
+            // Handle added dependencies
+            return Ok(Some(DependencyChange {
+                section,
+                name: String::from(name),
+                package: String::from(current.package),
+                previous_requirement: None,
+                current_requirement: current.requirement.map(String::from),
+                kind: Kind::Added,
+                change: Change::None,
+                relationship: None,
+                bounds: None,
+                current_span,
+                previous_span,
+            }));
+        };
+
+        // Handle dependencies in previous and current (filtering for ones with changed
+        // requirements)
+        let previous = DependencyRequirement::new(name, previous_value);
+        let previous_version = Self::get_version(previous_value)?;
+        let (current_source, previous_source) = (current_value.source(), previous_value.source());
+        let change = |kind, change, relationship, bounds| DependencyChange {
+            section,
+            name: String::from(name),
+            package: String::from(current.package),
+            previous_requirement: previous.requirement.map(String::from),
+            current_requirement: current.requirement.map(String::from),
+            kind,
+            change,
+            relationship,
+            bounds,
+            current_span,
+            previous_span,
+        };
+        if current_source != previous_source {
+            return Ok(Some(change(
+                Kind::SourceChanged {
+                    from: previous_source,
+                    to: current_source,
+                },
+                Change::None,
+                None,
+                None,
+            )));
         }
+        // Git dependencies from the same repository and revision have nothing to compare
+        let (Some(current_version), Some(previous_version)) = (current_version, previous_version)
+        else {
+            return Ok(None);
+        };
+        let relationship = current_version.relationship(&previous_version)?;
+        let kind = Kind::from_ordering(relationship.ordering());
+        // Requirements as written are compared, since the parsed ones may be identical (`1.0.0`
+        // and `^1.0.0`)
+        if kind == Kind::NotationOnly && current.requirement == previous.requirement {
+            return Ok(None);
+        }
+        if kind == Kind::NotationOnly {
+            return Ok(Some(change(kind, Change::None, None, None)));
+        }
+        let bounds = current_version.bounds_change(&previous_version)?;
+
+        Ok(Some(change(
+            kind,
+            bounds.change_type(),
+            Some(relationship),
+            Some(bounds),
+        )))
+    }
 
-        Ok(())
+    /// Unusual requirements are recorded as skipped, naming the dependency, section and location,
+    /// so that the rest of the report is still produced.  The error is located in the current
+    /// Cargo.toml, or the previous one for removed dependencies.
+    fn skipped_dependency_change(
+        section: Section,
+        name: &str,
+        (current_value, previous_value): (
+            Option<&CargoDependencyValue>,
+            Option<&CargoDependencyValue>,
+        ),
+        error: anyhow::Error,
+        (current_span, previous_span): (Option<Span>, Option<Span>),
+    ) -> DependencyChange {
+        let current = current_value.map(|value| DependencyRequirement::new(name, value));
+        let previous = previous_value.map(|value| DependencyRequirement::new(name, value));
+        let package = current
+            .as_ref()
+            .or(previous.as_ref())
+            .map_or(name, |requirement| requirement.package);
+        let error = match (current_span, previous_span) {
+            (Some(span), _) => error.context(format!("Cargo.toml {span}")),
+            (None, Some(span)) => error.context(format!("Previous Cargo.toml {span}")),
+            (None, None) => error,
+        };
+        log::error!("Skipping `{package}` in `[{section}]`: {error:#}");
+
+        DependencyChange {
+            section,
+            name: String::from(name),
+            package: String::from(package),
+            previous_requirement: previous
+                .and_then(|value| value.requirement)
+                .map(String::from),
+            current_requirement: current
+                .and_then(|value| value.requirement)
+                .map(String::from),
+            kind: Kind::Skipped(format!("{error:#}")),
+            change: Change::Unknown,
+            relationship: None,
+            bounds: None,
+            current_span,
+            previous_span,
+        }
     }
 
     fn get_dependency_changes_versus_previous(
-        current_dependencies: &BTreeMap<String, CargoDependencyValue>,
-        previous_dependencies: &BTreeMap<String, CargoDependencyValue>,
-        label: Option<&str>,
-        result: &mut String,
-    ) -> anyhow::Result<()> {
-        // Update incrementally eventually leaving only previous dependencies (that are no longer
-        // dependencies)
-        let mut previous_keys: BTreeSet<_> = previous_dependencies.keys().cloned().collect();
-
-        Self::get_changes_from_current_dependencies(
-            current_dependencies,
-            previous_dependencies,
-            label,
-            &mut previous_keys,
-            result,
-        )?;
+        &self,
+        previous: &Self,
+        current_dependencies: Option<&BTreeMap<String, CargoDependencyValue>>,
+        previous_dependencies: Option<&BTreeMap<String, CargoDependencyValue>>,
+        section: Section,
+        result: &mut ChangeSet,
+    ) {
+        let empty = BTreeMap::new();
+        let current_dependencies = current_dependencies.unwrap_or(&empty);
+        let previous_dependencies = previous_dependencies.unwrap_or(&empty);
+
+        for (name, current_value) in current_dependencies {
+            let spans = (
+                self.dependency_span(section, name),
+                previous.dependency_span(section, name),
+            );
+            match Self::get_change_from_current_dependency(
+                section,
+                name,
+                current_value,
+                previous_dependencies.get(name),
+                spans,
+            ) {
+                Ok(Some(change)) => result.dependencies.push(change),
+                Ok(None) => {}
+                Err(error) => result.dependencies.push(Self::skipped_dependency_change(
+                    section,
+                    name,
+                    (Some(current_value), previous_dependencies.get(name)),
+                    error,
+                    spans,
+                )),
+            }
+        }
 
         // Handle removed dependencies
-        for name in previous_keys {
-            let (version, package_name): (SemverVersion, &str) = match previous_dependencies
-                .get(&name)
-                .expect("Previous dependencies should include this dependency.")
-            {
-                CargoDependencyValue::Simple(version) => {
-                    let version = SemverVersion::new(version).unwrap();
-                    (version, &name)
-                }
-                CargoDependencyValue::Detailed(DetailedCargoDependency { package, version }) => {
-                    let version = SemverVersion::new(version)
-                        .expect("Previous dependencies should include this dependency.");
-                    let name = if let Some(package_value) = package {
-                        package_value
-                    } else {
-                        &name
-                    };
-                    (version, name)
-                }
-                CargoDependencyValue::Git(GitCargoDependency { git, package }) => {
-                    log::warn!("Git dependency `{git}` found, but version change detection for git dependencies is not currently supported");
-                    let version =
-                        SemverVersion::new("0").expect("`0` should be a valid semver version");
-                    let name = if let Some(package_value) = package {
-                        package_value
-                    } else {
-                        &name
-                    };
-                    (version, name)
-                }
-            };
-            if let Some(label_value) = label {
-                let _ = writeln!(result, "🗑️ remove {package_name} {label_value} {version}");
-            } else {
-                let _ = writeln!(result, "🗑️ remove {package_name} {version}");
+        for (name, previous_value) in previous_dependencies
+            .iter()
+            .filter(|(name, _)| !current_dependencies.contains_key(*name))
+        {
+            let previous_span = previous.dependency_span(section, name);
+            if let Err(error) = Self::get_version(previous_value) {
+                result.dependencies.push(Self::skipped_dependency_change(
+                    section,
+                    name,
+                    (None, Some(previous_value)),
+                    error,
+                    (None, previous_span),
+                ));
+                continue;
             }
+            let DependencyRequirement {
+                package,
+                requirement,
+            } = DependencyRequirement::new(name, previous_value);
+            result.dependencies.push(DependencyChange {
+                section,
+                name: name.clone(),
+                package: String::from(package),
+                previous_requirement: requirement.map(String::from),
+                current_requirement: None,
+                kind: Kind::Removed,
+                change: Change::None,
+                relationship: None,
+                bounds: None,
+                current_span: None,
+                previous_span,
+            });
         }
+    }
 
-        Ok(())
+    fn sections(&self) -> [(Section, Option<&BTreeMap<String, CargoDependencyValue>>); 4] {
+        [
+            (Section::Dependencies, self.dependencies.as_ref()),
+            (Section::DevDependencies, self.dev_dependencies.as_ref()),
+            (Section::BuildDependencies, self.build_dependencies.as_ref()),
+            (
+                Section::WorkspaceDependencies,
+                self.workspace_dependencies.as_ref(),
+            ),
+        ]
     }
 
-    fn get_optional_dependency_changes_versus_previous(
-        current_dependencies: Option<&BTreeMap<String, CargoDependencyValue>>,
-        previous_dependencies: Option<&BTreeMap<String, CargoDependencyValue>>,
-        label: Option<&str>,
-        result: &mut String,
-    ) -> anyhow::Result<()> {
-        match (current_dependencies, previous_dependencies) {
-            (Some(current_value), Some(previous_value)) => {
-                Self::get_dependency_changes_versus_previous(
-                    current_value,
-                    previous_value,
-                    label,
-                    result,
-                )?;
-            }
-            (Some(current_value), None) => {
-                let previous = BTreeMap::<String, CargoDependencyValue>::new();
-                Self::get_dependency_changes_versus_previous(
-                    current_value,
-                    &previous,
-                    label,
-                    result,
-                )?;
-            }
-            (None, Some(previous_value)) => {
-                let current = BTreeMap::<String, CargoDependencyValue>::new();
-                Self::get_dependency_changes_versus_previous(
-                    &current,
-                    previous_value,
-                    label,
-                    result,
-                )?;
-            }
-            (None, None) => {}
+    /// Typed record of dependency changes, in section order, and build target changes
+    pub fn changes_versus_previous_version(&self, previous: &Self) -> ChangeSet {
+        let mut result = ChangeSet {
+            target_changes: self
+                .targets
+                .changes_versus_previous_version(&previous.targets),
+            ..ChangeSet::default()
+        };
+        for ((section, current_dependencies), (_, previous_dependencies)) in
+            self.sections().into_iter().zip(previous.sections())
+        {
+            self.get_dependency_changes_versus_previous(
+                previous,
+                current_dependencies,
+                previous_dependencies,
+                section,
+                &mut result,
+            );
         }
-        Ok(())
+
+        result
     }
 
-    pub fn print_changes_versus_previous_version(&self, previous: &Self) -> anyhow::Result<String> {
-        let mut result: String = String::new();
-
-        Self::get_optional_dependency_changes_versus_previous(
-            self.dependencies.as_ref(),
-            previous.dependencies.as_ref(),
-            None,
-            &mut result,
-        )?;
-
-        Self::get_optional_dependency_changes_versus_previous(
-            self.dev_dependencies.as_ref(),
-            previous.dev_dependencies.as_ref(),
-            Some("(🖥️ dev-dependencies)"),
-            &mut result,
-        )?;
-
-        Self::get_optional_dependency_changes_versus_previous(
-            self.build_dependencies.as_ref(),
-            previous.build_dependencies.as_ref(),
-            Some("(🧱 build-dependencies)"),
-            &mut result,
-        )?;
-
-        Self::get_optional_dependency_changes_versus_previous(
-            self.workspace_dependencies.as_ref(),
-            previous.workspace_dependencies.as_ref(),
-            Some("(🗄️ workspace-dependencies)"),
-            &mut result,
-        )?;
-
-        if result.is_empty() {
-            return Ok(String::from("🧹 No changes detected.\n"));
-        }
+    /// Every direct dependency, in order, as `(section, key, requirement)`
+    pub fn section_requirements(&self) -> Vec<(Section, &str, DependencyRequirement<'_>)> {
+        self.sections()
+            .into_iter()
+            .flat_map(|(section, dependencies)| {
+                dependencies
+                    .into_iter()
+                    .flatten()
+                    .map(move |(name, value)| {
+                        (
+                            section,
+                            name.as_str(),
+                            DependencyRequirement::new(name, value),
+                        )
+                    })
+            })
+            .collect()
+    }
 
-        Ok(result)
+    /// Every dependency, in any section, with a requirement no version can satisfy.  `revision`
+    /// names the Cargo.toml version in the output.  Requirements which cannot be parsed or
+    /// compared are left for the change report to flag.
+    pub fn unsatisfiable_requirements(&self, revision: &str) -> Vec<UnsatisfiableRequirement> {
+        self.section_requirements()
+            .into_iter()
+            .filter_map(|(section, _, dependency)| {
+                let version = SemverVersion::new(dependency.requirement?).ok()?;
+                matches!(version.is_satisfiable(), Ok(false)).then(|| UnsatisfiableRequirement {
+                    section,
+                    package: String::from(dependency.package),
+                    requirement: version.to_string(),
+                    revision: String::from(revision),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Dependency requirement which no version can satisfy, for example `>=2, <1.5`
+#[derive(Debug, PartialEq)]
+pub struct UnsatisfiableRequirement {
+    pub section: Section,
+    pub package: String,
+    pub requirement: String,
+
+    /// Cargo.toml version the requirement appears in, for example `working tree`
+    pub revision: String,
+}
+
+impl UnsatisfiableRequirement {
+    /// Text report line, without a trailing newline
+    pub fn fmt_line(&self, style: Style) -> String {
+        format!(
+            "{} {} [{}] requirement {} ({})",
+            style.mark(Mark::Unsatisfiable),
+            self.package,
+            self.section,
+            self.requirement,
+            self.revision
+        )
+    }
+}
+
+/// Package and version requirement for a single direct dependency.  `requirement` is `None` for
+/// git dependencies.
+#[derive(Debug, PartialEq)]
+pub struct DependencyRequirement<'a> {
+    pub package: &'a str,
+    pub requirement: Option<&'a str>,
+}
+
+impl<'a> DependencyRequirement<'a> {
+    fn new(name: &'a str, value: &'a CargoDependencyValue) -> Self {
+        match value {
+            CargoDependencyValue::Simple(version) => Self {
+                package: name,
+                requirement: Some(version),
+            },
+            CargoDependencyValue::Detailed(DetailedCargoDependency {
+                version, package, ..
+            }) => Self {
+                package: package.as_deref().unwrap_or(name),
+                requirement: Some(version),
+            },
+            CargoDependencyValue::Git(GitCargoDependency { package, .. }) => Self {
+                package: package.as_deref().unwrap_or(name),
+                requirement: None,
+            },
+        }
     }
 }
 
@@ -346,15 +634,46 @@ pub struct DetailedCargoDependency {
     #[allow(dead_code)]
     version: String,
     package: Option<String>,
+    #[serde(flatten)]
+    git: Option<GitReference>,
+    path: Option<String>,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[cfg_attr(test, derive(serde::Serialize))]
 pub struct GitCargoDependency {
-    // #[allow(dead_code, reason = "Field needed for deserialisation")]
-    #[allow(dead_code)]
-    git: String,
     package: Option<String>,
+    #[serde(flatten)]
+    git: GitReference,
+}
+
+/// Git repository and revision of a git dependency
+#[derive(Debug, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(serde::Serialize))]
+pub struct GitReference {
+    git: String,
+    branch: Option<String>,
+    tag: Option<String>,
+    rev: Option<String>,
+}
+
+impl From<&GitReference> for DependencySource {
+    fn from(reference: &GitReference) -> Self {
+        let GitReference {
+            git,
+            branch,
+            tag,
+            rev,
+        } = reference;
+        let reference = [("branch", branch), ("tag", tag), ("rev", rev)]
+            .into_iter()
+            .find_map(|(label, value)| Some(format!("{label} {}", value.as_ref()?)));
+
+        DependencySource::Git {
+            url: git.clone(),
+            reference,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -374,18 +693,113 @@ pub enum CargoDependencyValue {
     Git(GitCargoDependency),
 }
 
+impl CargoDependencyValue {
+    /// Path dependencies with a version resolve to the registry version once published, but to
+    /// the path locally, so count as path dependencies
+    fn source(&self) -> DependencySource {
+        match self {
+            CargoDependencyValue::Detailed(DetailedCargoDependency {
+                git: Some(reference),
+                ..
+            })
+            | CargoDependencyValue::Git(GitCargoDependency { git: reference, .. }) => {
+                DependencySource::from(reference)
+            }
+            CargoDependencyValue::Detailed(DetailedCargoDependency {
+                path: Some(path), ..
+            }) => DependencySource::Path(path.clone()),
+            CargoDependencyValue::Simple(_) | CargoDependencyValue::Detailed(_) => {
+                DependencySource::Registry
+            }
+        }
+    }
+}
+
+/// Dependency keys and values, with their spans in the source, and value contents ignored
+type SpannedDependencies = Option<BTreeMap<Spanned<String>, Spanned<IgnoredAny>>>;
+
+#[derive(Debug, Deserialize)]
+struct SpannedCargoWorkspace {
+    dependencies: SpannedDependencies,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct SpannedCargoFile {
+    dependencies: SpannedDependencies,
+    build_dependencies: SpannedDependencies,
+    dev_dependencies: SpannedDependencies,
+    workspace: Option<SpannedCargoWorkspace>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(test, derive(serde::Serialize))]
 pub struct CargoWorkspace {
     pub dependencies: Option<BTreeMap<String, CargoDependencyValue>>,
+    pub members: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(test, derive(serde::Serialize))]
+pub struct CargoPackage {
+    pub name: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[cfg_attr(test, derive(serde::Serialize))]
 #[serde(rename_all = "kebab-case")]
 pub struct CargoFile {
+    pub package: Option<CargoPackage>,
     pub dependencies: Option<BTreeMap<String, CargoDependencyValue>>,
     pub build_dependencies: Option<BTreeMap<String, CargoDependencyValue>>,
     pub dev_dependencies: Option<BTreeMap<String, CargoDependencyValue>>,
     pub workspace: Option<CargoWorkspace>,
+    pub lib: Option<BuildTarget>,
+    pub bin: Option<Vec<BuildTarget>>,
+    pub example: Option<Vec<BuildTarget>>,
+    pub test: Option<Vec<BuildTarget>>,
+    pub bench: Option<Vec<BuildTarget>>,
+}
+
+impl From<CargoFile> for File {
+    fn from(cargo_file: CargoFile) -> Self {
+        let CargoFile {
+            package,
+            dependencies,
+            build_dependencies,
+            dev_dependencies,
+            workspace,
+            lib,
+            bin,
+            example,
+            test,
+            bench,
+        } = cargo_file;
+
+        let (workspace_dependencies, workspace_members) = if let Some(workspace_val) = workspace {
+            (
+                workspace_val.dependencies,
+                workspace_val.members.unwrap_or_default(),
+            )
+        } else {
+            (None, Vec::new())
+        };
+
+        Self {
+            dependencies,
+            build_dependencies,
+            dev_dependencies,
+            workspace_dependencies,
+            targets: BuildTargets {
+                lib,
+                bin: bin.unwrap_or_default(),
+                example: example.unwrap_or_default(),
+                test: test.unwrap_or_default(),
+                bench: bench.unwrap_or_default(),
+            },
+            package_name: package.map(|value| value.name),
+            workspace_members,
+            dependency_spans: BTreeMap::new(),
+        }
+    }
 }
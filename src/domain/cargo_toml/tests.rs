@@ -5,7 +5,13 @@ use assert_fs::{
     TempDir,
 };
 
-use crate::domain::cargo_toml::{CargoDependencyValue, DetailedCargoDependency};
+use crate::domain::{
+    cargo_toml::{CargoDependencyValue, DetailedCargoDependency},
+    change_set::{Kind, Section},
+    diagnostic::{Code, Diagnostic},
+    semver::Change,
+    Style, Template,
+};
 
 use super::File;
 
@@ -66,7 +72,9 @@ fn new_successfully_parses_valid_cargo_toml_dependencies() {
         dependencies_value.get("serde"),
         Some(CargoDependencyValue::Detailed(DetailedCargoDependency {
             version: String::from("1.0.215"),
-            package: None
+            package: None,
+            git: None,
+            path: None,
         }))
         .as_ref()
     );
@@ -74,7 +82,9 @@ fn new_successfully_parses_valid_cargo_toml_dependencies() {
         dependencies_value.get("sqlx"),
         Some(CargoDependencyValue::Detailed(DetailedCargoDependency {
             version: String::from("0.8.2"),
-            package: None
+            package: None,
+            git: None,
+            path: None,
         }))
         .as_ref()
     );
@@ -107,15 +117,114 @@ fn new_handles_missing_cargo_toml() {
         ))
     );
     assert_eq!(
-        chain.next().map(|val| format!("{val}")),
-        Some(format!(
-            r#"configuration file "{}" not found"#,
-            temporary_cargo_toml_path.display()
-        ))
+        chain
+            .next()
+            .and_then(|val| val.downcast_ref::<std::io::Error>())
+            .map(std::io::Error::kind),
+        Some(std::io::ErrorKind::NotFound)
     );
     assert!(chain.next().is_none());
 }
 
+#[test]
+fn new_reports_invalid_toml_with_location_in_working_tree() {
+    // arrange
+    let temp_dir = assert_fs::TempDir::new().unwrap();
+    let cargo_toml = temp_dir.child("Cargo.toml");
+    cargo_toml
+        .write_str("[package]\nname = \"example\"\n\n[dependencies]\nlog = \"0.4\n")
+        .unwrap();
+    let path = cargo_toml.to_str().unwrap();
+
+    // act
+    let outcome = File::new(path).unwrap_err();
+
+    // assert
+    let diagnostic = outcome.downcast_ref::<Diagnostic>().unwrap();
+    assert_eq!(diagnostic.code, Code::InvalidToml);
+    assert_eq!(
+        format!("{diagnostic}"),
+        format!(
+            "error[RCD001]: invalid basic string, expected `\"`
+ --> {path}:5:11 (working tree)
+  |
+5 | log = \"0.4
+  |           ^"
+        )
+    );
+}
+
+#[test]
+fn new_from_buffer_explains_inherited_dependencies() {
+    // arrange
+    let buffer = br#"[package]
+name = "example"
+
+[dependencies]
+log = "0.4.22"
+
+[dependencies.serde]
+workspace = true
+features = ["derive"]
+"#;
+
+    // act
+    let outcome = File::new_from_buffer(buffer, "HEAD").unwrap_err();
+
+    // assert
+    let diagnostic = outcome.downcast_ref::<Diagnostic>().unwrap();
+    assert_eq!(diagnostic.code, Code::InheritedDependency);
+    assert_eq!(
+        format!("{diagnostic}"),
+        String::from(
+            "error[RCD003]: inherited dependency `serde` in `[dependencies]`
+ --> Cargo.toml:7:15 (HEAD)
+  |
+7 | [dependencies.serde]
+  |               ^^^^^
+  |
+  = help: inherited `workspace = true` entries not supported"
+        )
+    );
+}
+
+#[test]
+fn new_from_str_classifies_unsupported_dependency_entries() {
+    // arrange
+    let path_dependency = "[dev-dependencies]\nlocal = { path = \"../local\" }\n";
+    let unsupported_dependency = "[workspace.dependencies]\nodd = { branch = \"main\" }\n";
+    let unexpected_value = "[package]\nname = \"example\"\n\n[lib]\npath = 3\n";
+
+    // act
+    let codes: Vec<(Code, String)> = [path_dependency, unsupported_dependency, unexpected_value]
+        .into_iter()
+        .map(|toml_str| {
+            let outcome = File::new_from_str(toml_str).unwrap_err();
+            let diagnostic = outcome.downcast_ref::<Diagnostic>().unwrap();
+            (diagnostic.code, diagnostic.message.clone())
+        })
+        .collect();
+
+    // assert
+    assert_eq!(
+        codes,
+        vec![
+            (
+                Code::PathDependency,
+                String::from("path dependency `local` in `[dev-dependencies]` has no version")
+            ),
+            (
+                Code::UnsupportedDependency,
+                String::from("unsupported dependency `odd` in `[workspace.dependencies]`")
+            ),
+            (
+                Code::UnexpectedValue,
+                String::from("invalid type: integer `3`, expected a string")
+            ),
+        ]
+    );
+}
+
 #[test]
 fn new_accepts_missing_dependencies_in_cargo_toml() {
     // arrange
@@ -145,18 +254,18 @@ trycmd = "0.15.8"
 }
 
 #[test]
-fn print_changes_versus_previous_version_advises_when_there_are_no_changes() {
+fn print_changes_is_empty_when_there_are_no_changes() {
     let temp_dir = assert_fs::TempDir::new().unwrap();
     let temporary_cargo_toml_path = get_temporary_cargo_toml_path(&temp_dir);
     let cargo_toml_file = File::new(temporary_cargo_toml_path.to_str().unwrap()).unwrap();
 
     // act
     let output = cargo_toml_file
-        .print_changes_versus_previous_version(&cargo_toml_file)
-        .unwrap();
+        .changes_versus_previous_version(&cargo_toml_file)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
-    assert_eq!(output, String::from("🧹 No changes detected.\n"));
+    assert_eq!(output, String::new());
 }
 
 #[test]
@@ -225,15 +334,15 @@ trycmd = "0.15.8"
     let earlier_cargo_toml = File::new_from_str(earlier_cargo_toml_content).unwrap();
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
         output,
         String::from(
-            "📦 bump ahash from 0.8.10 to 0.8.11
-🔧 bump serde from 1.0.210 to 1.0.215
+            "📦 bump ahash from 0.8.10 to 0.8.11 (floor raised minor)
+🔧 bump serde from 1.0.210 to 1.0.215 (floor raised patch)
 "
         )
     );
@@ -306,14 +415,15 @@ trycmd = "0.15.8"
 
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
         output,
         String::from(
-            "📦 bump ahash from 0.8.10 to 0.8.11\n🔧 drop serde from 1.0.215 to 1.0.210\n"
+            "📦 bump ahash from 0.8.10 to 0.8.11 (floor raised minor)\n\
+                🔧 drop serde from 1.0.215 to 1.0.210 (floor lowered patch)\n"
         )
     );
 }
@@ -385,13 +495,16 @@ trycmd = "0.15.8"
 
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
         output,
-        String::from("📦 bump ahash from 0.8.10 to 0.8.11\n🤷 drop serde from 1.0.215 to 1\n")
+        String::from(
+            "📦 bump ahash from 0.8.10 to 0.8.11 (floor raised minor)\n\
+                🔧 drop serde from 1.0.215 to 1 (floor lowered patch)\n"
+        )
     );
 }
 
@@ -471,17 +584,21 @@ wiremock = "0.6.2"
 
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
         output,
         String::from(
-            "📦 bump ahash from 0.8.10 to 0.8.11\n✨ add serde 1\n🗑\u{fe0f} remove image 0.25.5\n\
-                    ❗ bump assert_fs (🖥\u{fe0f} dev-dependencies) from 0 to 1.1.2\n\
+            "📦 bump ahash from 0.8.10 to 0.8.11 (floor raised minor)\n✨ add serde 1\n🗑\u{fe0f} remove image 0.25.5\n\
+                    ❗ bump assert_fs (🖥\u{fe0f} dev-dependencies) from 0 to 1.1.2 (floor raised major, \
+                    ceiling raised major)\n\
+                    ✏\u{fe0f} normalise notation float-cmp (🖥\u{fe0f} dev-dependencies) from \
+                    `0.10.0` to `0.10`\n\
                     ✨ add proptest (🖥\u{fe0f} dev-dependencies) 1.6.0\n\
-                    ❗ drop trycmd (🖥\u{fe0f} dev-dependencies) from 0.15.8 to 0.14\n\
+                    ❗ drop trycmd (🖥\u{fe0f} dev-dependencies) from 0.15.8 to 0.14 (floor lowered major, \
+                    ceiling lowered major)\n\
                     🗑\u{fe0f} remove wiremock (🖥\u{fe0f} dev-dependencies) 0.6.2\n\
                     ✨ add anyhow (🧱 build-dependencies) 1.0.95\n\
                     ✨ add fs_extra (🧱 build-dependencies) 1.3.0\n\
@@ -557,8 +674,8 @@ glob = "0.3.1"
 
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
@@ -620,15 +737,17 @@ serde = { version = "0", features = ["derive"] }
 
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
         output,
         String::from(
-            "❗ bump ahash (🗄\u{fe0f} workspace-dependencies) from 0.7 to 0.8.11\n\
-                ❗ bump serde (🗄\u{fe0f} workspace-dependencies) from 0 to 1\n"
+            "❗ bump ahash (🗄\u{fe0f} workspace-dependencies) from 0.7 to 0.8.11 (floor raised major, \
+                ceiling raised major)\n\
+                ❗ bump serde (🗄\u{fe0f} workspace-dependencies) from 0 to 1 (floor raised major, ceiling \
+                raised major)\n"
         )
     );
 }
@@ -676,7 +795,7 @@ fn new_from_buffer_creates_expected_config() {
     ];
 
     // act
-    let outcome = File::new_from_buffer(&buffer).unwrap();
+    let outcome = File::new_from_buffer(&buffer, "HEAD").unwrap();
 
     // assert
     insta::assert_snapshot!(format!("{outcome:?}"));
@@ -720,14 +839,15 @@ getrandom2 = { package = "getrandom", version = "0.2.1", features = ["js"] }
 
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
         output,
         String::from(
-            "🤷 bump getrandom from 0.3 to 0.3.2\n📦 bump getrandom from 0.2.1 to 0.2.15\n"
+            "📦 bump getrandom from 0.3 to 0.3.2 (floor raised minor)\n📦 bump getrandom from 0.2.1 to \
+                0.2.15 (floor raised minor)\n"
         )
     );
 }
@@ -771,8 +891,8 @@ getrandom_previous = { package = "getrandom", version = "0.2.1", features = ["js
 
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
@@ -811,12 +931,560 @@ reqwest_client = { git = "https://github.com/zed-industries/zed", rev = "a3f0701
 
     // act
     let output = updated_cargo_toml
-        .print_changes_versus_previous_version(&earlier_cargo_toml)
-        .unwrap();
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "🔀 switch source gpui from git https://github.com/zed-industries/zed (rev \
+                a3f070195111f8d80111cd73b8a26d7aa2228040) to git \
+                https://github.com/zed-industries/zed (rev \
+                f1af2a4a58b4e48a0ce442181120859cd4df4b30)\n\
+                ✨ add http_client 0\n\
+                🗑\u{fe0f} remove reqwest_client 0\n"
+        )
+    );
+}
+
+#[test]
+fn changes_versus_previous_version_reports_source_changes_without_severity() {
+    // arrange
+    let updated_cargo_toml = File::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+
+[dependencies]
+anyhow = "1.0.95"
+log = { git = "https://github.com/rust-lang/log", branch = "main" }
+serde = { version = "1.0.215", path = "../serde" }
+thiserror = { git = "https://github.com/dtolnay/thiserror", tag = "2.0.11" }
+"#,
+    )
+    .unwrap();
+    let earlier_cargo_toml = File::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+
+[dependencies]
+anyhow = { git = "https://github.com/dtolnay/anyhow" }
+log = "0.4.22"
+serde = "1.0.215"
+thiserror = { git = "https://github.com/dtolnay/thiserror", tag = "2.0.9" }
+"#,
+    )
+    .unwrap();
+
+    // act
+    let change_set = updated_cargo_toml.changes_versus_previous_version(&earlier_cargo_toml);
+    let output = change_set.print_changes(Template::builtin(Style::Plain), false);
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "SOURCE anyhow from git https://github.com/dtolnay/anyhow to registry\n\
+                SOURCE log from registry to git https://github.com/rust-lang/log (branch main)\n\
+                SOURCE serde from registry to path ../serde\n\
+                SOURCE thiserror from git https://github.com/dtolnay/thiserror (tag 2.0.9) to git \
+                https://github.com/dtolnay/thiserror (tag 2.0.11)\n"
+        )
+    );
+    assert!(change_set
+        .dependencies
+        .iter()
+        .all(|change| change.change == Change::None && !change.is_at_least(&Change::Patch)));
+    let git_sources: Vec<&str> = change_set
+        .dependencies
+        .iter()
+        .filter(|change| change.is_git_source())
+        .map(|change| change.package.as_str())
+        .collect();
+    assert_eq!(git_sources, vec!["log", "thiserror"]);
+}
+
+#[test]
+fn print_changes_lists_target_changes_after_dependency_changes() {
+    // arrange
+    let updated_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+crate-type = ["cdylib", "rlib"]
+
+[[bin]]
+name = "some-example-cli"
+path = "src/main.rs"
+
+[[bin]]
+name = "some-example-server"
+path = "src/bin/server.rs"
+required-features = ["server"]
+
+[dependencies]
+ahash = "0.8.11"
+"#;
+
+    let earlier_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[[bin]]
+name = "some-example"
+path = "src/main.rs"
+
+[[bin]]
+name = "some-example-server"
+path = "src/bin/server.rs"
+
+[[example]]
+name = "demo"
+
+[dependencies]
+ahash = "0.8.10"
+"#;
+    let updated_cargo_toml = File::new_from_str(updated_cargo_toml_content).unwrap();
+    let earlier_cargo_toml = File::new_from_str(earlier_cargo_toml_content).unwrap();
+
+    // act
+    let output = updated_cargo_toml
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "📦 bump ahash from 0.8.10 to 0.8.11 (floor raised minor)\n\
+                ✨ add lib target\n\
+                🏷\u{fe0f} rename bin target some-example to some-example-cli\n\
+                🎯 change bin target some-example-server required-features from default to [server]\n\
+                🗑\u{fe0f} remove example target demo\n"
+        )
+    );
+}
+
+#[test]
+fn new_parses_build_targets() {
+    // arrange
+    let temp_dir = assert_fs::TempDir::new().unwrap();
+    let cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+proc-macro = true
+
+[[bench]]
+name = "parse"
+harness = false
+"#;
+    let _ = temp_dir.child("Cargo.toml").write_str(cargo_toml_content);
+    let temporary_cargo_toml_path = temp_dir.join("Cargo.toml");
+
+    // act
+    let File { targets, .. } = File::new(temporary_cargo_toml_path.to_str().unwrap()).unwrap();
+
+    // assert
+    assert!(targets.lib.is_some());
+    assert_eq!(targets.bench.len(), 1);
+    assert!(targets.bin.is_empty());
+}
+
+#[test]
+fn print_dependency_changes_displays_prerelease_bump() {
+    // arrange
+    let updated_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+leptos = "1.0.0-beta.2"
+"#;
+    let earlier_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+leptos = "1.0.0-alpha.1"
+"#;
+    let updated_cargo_toml = File::new_from_str(updated_cargo_toml_content).unwrap();
+    let earlier_cargo_toml = File::new_from_str(earlier_cargo_toml_content).unwrap();
+
+    // act
+    let output = updated_cargo_toml
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "🧪 bump leptos from 1.0.0-alpha.1 to 1.0.0-beta.2 (floor raised pre-release)\n"
+        )
+    );
+}
+
+#[test]
+fn print_dependency_changes_describes_changes_which_are_neither_bumps_nor_drops() {
+    // arrange
+    let updated_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+ahash = ">=0.8.1, <0.8.12"
+serde = ">=1.0.150, <1.0.220"
+"#;
+    let earlier_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+ahash = ">=0.8.5, <0.8.10"
+serde = ">=1.0.100, <1.0.200"
+"#;
+    let updated_cargo_toml = File::new_from_str(updated_cargo_toml_content).unwrap();
+    let earlier_cargo_toml = File::new_from_str(earlier_cargo_toml_content).unwrap();
+
+    // act
+    let output = updated_cargo_toml
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "📦 change ahash from >=0.8.5, <0.8.10 to >=0.8.1, <0.8.12 (widened; floor lowered \
+                minor, ceiling raised minor)\n\
+                🔧 change serde from >=1.0.100, <1.0.200 to >=1.0.150, <1.0.220 (shifted up; \
+                floor raised patch, ceiling raised patch)\n"
+        )
+    );
+}
+
+#[test]
+fn print_dependency_changes_reports_unusual_requirements_and_continues() {
+    // arrange
+    let updated_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0.90"
+
+[dev-dependencies]
+huge = "18446744073709551615"
+insta = "1.41.0"
+"#;
+    let earlier_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0.89"
+legacy = "not-a-version"
+
+[dev-dependencies]
+huge = "1"
+insta = "1.40.0"
+"#;
+    let updated_cargo_toml = File::new_from_str(updated_cargo_toml_content).unwrap();
+    let earlier_cargo_toml = File::new_from_str(earlier_cargo_toml_content).unwrap();
+
+    // act
+    let output = updated_cargo_toml
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
 
     // assert
     assert_eq!(
         output,
-        String::from("✨ add http_client 0\n🗑\u{fe0f} remove reqwest_client 0\n")
+        String::from(
+            "🔧 bump anyhow from 1.0.89 to 1.0.90 (floor raised patch)\n\
+                ⚠️ skip legacy [dependencies]: Previous Cargo.toml line 8, column 1: Unexpected \
+                semver not-a-version found while computing dependency changes: unexpected \
+                character 'n' while parsing major version number\n\
+                ⚠️ skip huge [dev-dependencies]: Cargo.toml line 10, column 1: Unexpectedly high \
+                major version: `18446744073709551615`\n\
+                📦 bump insta (🖥️ dev-dependencies) from 1.40.0 to 1.41.0 (floor raised minor)\n"
+        )
+    );
+}
+
+#[test]
+fn changes_versus_previous_version_keeps_requirements_of_skipped_dependencies() {
+    // arrange
+    let updated_cargo_toml = File::new_from_str(
+        r#"[dependencies]
+huge = "18446744073709551615"
+"#,
+    )
+    .unwrap();
+    let earlier_cargo_toml = File::new_from_str(
+        r#"[dependencies]
+huge = "1"
+legacy = "not-a-version"
+"#,
+    )
+    .unwrap();
+
+    // act
+    let change_set = updated_cargo_toml.changes_versus_previous_version(&earlier_cargo_toml);
+
+    // assert
+    let requirements: Vec<_> = change_set
+        .dependencies
+        .iter()
+        .map(|change| {
+            (
+                change.name.as_str(),
+                change.previous_requirement.as_deref(),
+                change.current_requirement.as_deref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        requirements,
+        vec![
+            ("huge", Some("1"), Some("18446744073709551615")),
+            ("legacy", Some("not-a-version"), None),
+        ]
     );
 }
+
+#[test]
+fn unsatisfiable_requirements_lists_requirements_no_version_satisfies() {
+    // arrange
+    let cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+ahash = ">=2, <1.5"
+anyhow = "1.0.89"
+leptos = { version = ">=1.0.0-rc.1, <1.0.0-rc.1", package = "leptos-core" }
+
+[build-dependencies]
+cc = ">1.1.0, <1.1.1"
+
+[workspace.dependencies]
+tokio = { git = "https://github.com/tokio-rs/tokio" }
+"#;
+    let cargo_toml = File::new_from_str(cargo_toml_content).unwrap();
+
+    // act
+    let output: String = cargo_toml
+        .unsatisfiable_requirements("working tree")
+        .iter()
+        .map(|requirement| requirement.fmt_line(Style::Emoji) + "\n")
+        .collect();
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "❌ unsatisfiable ahash [dependencies] requirement >=2, <1.5 (working tree)\n\
+                ❌ unsatisfiable leptos-core [dependencies] requirement >=1.0.0-rc.1, \
+                <1.0.0-rc.1 (working tree)\n\
+                ❌ unsatisfiable cc [build-dependencies] requirement >1.1.0, <1.1.1 (working \
+                tree)\n"
+        )
+    );
+}
+
+#[test]
+fn print_dependency_changes_reports_notation_only_changes_unless_hidden() {
+    // arrange
+    let updated_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "^1.0.0"
+serde = "1.0"
+
+[build-dependencies]
+cc = { version = "~1.2", package = "cc-rs" }
+"#;
+    let earlier_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0.0"
+serde = "1"
+
+[build-dependencies]
+cc = { version = "1.2.*", package = "cc-rs" }
+"#;
+    let updated_cargo_toml = File::new_from_str(updated_cargo_toml_content).unwrap();
+    let earlier_cargo_toml = File::new_from_str(earlier_cargo_toml_content).unwrap();
+
+    // act
+    let output = updated_cargo_toml
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), false);
+    let hidden_output = updated_cargo_toml
+        .changes_versus_previous_version(&earlier_cargo_toml)
+        .print_changes(Template::builtin(Style::Emoji), true);
+
+    // assert
+    assert_eq!(
+        output,
+        String::from(
+            "✏\u{fe0f} normalise notation anyhow from `1.0.0` to `^1.0.0`\n\
+                ✏\u{fe0f} normalise notation serde from `1` to `1.0`\n\
+                ✏\u{fe0f} normalise notation cc-rs (🧱 build-dependencies) from `1.2.*` to \
+                `~1.2`\n"
+        )
+    );
+    assert_eq!(hidden_output, String::new());
+}
+
+#[test]
+fn changes_versus_previous_version_returns_typed_records() {
+    // arrange
+    let updated_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+rand = { version = "0.9", package = "rand-core" }
+
+[dev-dependencies]
+insta = "1.41.0"
+"#;
+    let earlier_cargo_toml_content = r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+rand = { version = "0.8", package = "rand-core" }
+"#;
+    let updated_cargo_toml = File::new_from_str(updated_cargo_toml_content).unwrap();
+    let earlier_cargo_toml = File::new_from_str(earlier_cargo_toml_content).unwrap();
+
+    // act
+    let outcome = updated_cargo_toml.changes_versus_previous_version(&earlier_cargo_toml);
+
+    // assert
+    let records: Vec<_> = outcome
+        .dependencies
+        .iter()
+        .map(|value| {
+            (
+                value.section,
+                value.name.as_str(),
+                value.package.as_str(),
+                value.previous_requirement.as_deref(),
+                value.current_requirement.as_deref(),
+                &value.kind,
+                &value.change,
+            )
+        })
+        .collect();
+    assert_eq!(
+        records,
+        vec![
+            (
+                Section::Dependencies,
+                "rand",
+                "rand-core",
+                Some("0.8"),
+                Some("0.9"),
+                &Kind::Bumped,
+                &Change::Major
+            ),
+            (
+                Section::DevDependencies,
+                "insta",
+                "insta",
+                None,
+                Some("1.41.0"),
+                &Kind::Added,
+                &Change::None
+            ),
+        ]
+    );
+}
+
+#[test]
+fn changes_versus_previous_version_records_dependency_spans() {
+    // arrange
+    let earlier_cargo_toml = File::new_from_str(
+        r#"[package]
+name = "example"
+
+[dependencies]
+log = "0.4.21"
+git2 = "0.19.0"
+"#,
+    )
+    .unwrap();
+    let updated_cargo_toml = File::new_from_str(
+        r#"[package]
+name = "example"
+
+[dependencies]
+log = "0.4.22"
+
+[dependencies.serde]
+version = "1.0.215"
+features = ["derive"]
+
+[workspace.dependencies]
+insta = "1.41"
+"#,
+    )
+    .unwrap();
+
+    // act
+    let change_set = updated_cargo_toml.changes_versus_previous_version(&earlier_cargo_toml);
+
+    // assert
+    let locations: Vec<_> = change_set
+        .dependencies
+        .iter()
+        .map(|change| {
+            (
+                change.name.as_str(),
+                change.current_span.map(|span| (span.line, span.column)),
+                change.previous_span.map(|span| (span.line, span.column)),
+            )
+        })
+        .collect();
+    assert_eq!(
+        locations,
+        vec![
+            ("log", Some((5, 1)), Some((5, 1))),
+            ("serde", Some((7, 15)), None),
+            ("git2", None, Some((6, 1))),
+            ("insta", Some((12, 1)), None),
+        ]
+    );
+    let log_span = updated_cargo_toml
+        .dependency_span(Section::Dependencies, "log")
+        .unwrap();
+    assert_eq!((log_span.start, log_span.end), (43, 57));
+    assert_eq!(log_span.to_string(), "line 5, column 1");
+}
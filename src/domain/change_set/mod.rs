@@ -0,0 +1,981 @@
+#[cfg(test)]
+mod tests;
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Write as _},
+    path::Path,
+};
+
+use anyhow::Context;
+use serde::Serialize;
+
+use super::{
+    build_target::{TargetAction, TargetChange},
+    cargo_lock::{DuplicateChange, LockedVersionChange, PackageChange, SupplyChainChange},
+    cargo_toml::{Span, UnsatisfiableRequirement},
+    semver::{BoundsChange, Change, RangeRelationship},
+    SemverVersion, Style, Template,
+};
+
+/// Cargo.toml dependency table
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Section {
+    Dependencies,
+    DevDependencies,
+    BuildDependencies,
+    WorkspaceDependencies,
+}
+
+impl Section {
+    pub const ALL: [Section; 4] = [
+        Section::Dependencies,
+        Section::DevDependencies,
+        Section::BuildDependencies,
+        Section::WorkspaceDependencies,
+    ];
+
+    /// Label used in text reports, where `[dependencies]` has none
+    pub fn label(self, style: Style) -> Option<&'static str> {
+        match (self, style) {
+            (Section::Dependencies, _) => None,
+            (Section::DevDependencies, Style::Emoji) => Some("(🖥️ dev-dependencies)"),
+            (Section::BuildDependencies, Style::Emoji) => Some("(🧱 build-dependencies)"),
+            (Section::WorkspaceDependencies, Style::Emoji) => Some("(🗄️ workspace-dependencies)"),
+            (Section::DevDependencies, Style::Plain) => Some("(dev-dependencies)"),
+            (Section::BuildDependencies, Style::Plain) => Some("(build-dependencies)"),
+            (Section::WorkspaceDependencies, Style::Plain) => Some("(workspace-dependencies)"),
+        }
+    }
+}
+
+/// Table name, as written in Cargo.toml
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Section::Dependencies => "dependencies",
+            Section::DevDependencies => "dev-dependencies",
+            Section::BuildDependencies => "build-dependencies",
+            Section::WorkspaceDependencies => "workspace.dependencies",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Where a dependency comes from, as written in Cargo.toml
+#[derive(Clone, Debug, PartialEq)]
+pub enum DependencySource {
+    Registry,
+
+    /// Repository URL, with the branch, tag or revision, if any, for example `rev 9f1c2e0`
+    Git {
+        url: String,
+        reference: Option<String>,
+    },
+
+    Path(String),
+}
+
+impl fmt::Display for DependencySource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DependencySource::Registry => write!(f, "registry"),
+            DependencySource::Git {
+                url,
+                reference: Some(reference),
+            } => write!(f, "git {url} ({reference})"),
+            DependencySource::Git {
+                url,
+                reference: None,
+            } => write!(f, "git {url}"),
+            DependencySource::Path(path) => write!(f, "path {path}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Kind {
+    Added,
+    Removed,
+
+    /// New requirement orders above the previous one
+    Bumped,
+
+    /// New requirement orders below the previous one
+    Dropped,
+
+    /// New requirement neither clearly above nor below the previous one, for example a range
+    /// widened at both ends
+    Changed,
+
+    /// Same matching versions, written differently, for example `1` and `1.0`
+    NotationOnly,
+
+    /// Dependency switched source, for example from the registry to git, or to another git
+    /// revision.  Requirements are not compared, since versions from different sources are
+    /// unrelated.
+    SourceChanged {
+        from: DependencySource,
+        to: DependencySource,
+    },
+
+    /// Requirement could not be parsed or compared, with the reason
+    Skipped(String),
+}
+
+impl Kind {
+    /// Kind for a requirement change, given the ordering of the new requirement relative to the
+    /// previous one
+    pub fn from_ordering(ordering: Option<Ordering>) -> Self {
+        match ordering {
+            Some(Ordering::Greater) => Kind::Bumped,
+            Some(Ordering::Less) => Kind::Dropped,
+            Some(Ordering::Equal) => Kind::NotationOnly,
+            None => Kind::Changed,
+        }
+    }
+
+    /// Value used for `direction` in JSON output and templates
+    pub fn direction(&self) -> &'static str {
+        match self {
+            Kind::Added => "add",
+            Kind::Removed => "remove",
+            Kind::Bumped => "bump",
+            Kind::Dropped => "drop",
+            Kind::Changed => "change",
+            Kind::NotationOnly => "notation",
+            Kind::SourceChanged { .. } => "source",
+            Kind::Skipped(_) => "skip",
+        }
+    }
+}
+
+/// Change to a single dependency in a single Cargo.toml section.  Requirements are as written in
+/// Cargo.toml, and are `None` where the dependency is absent, or is a git dependency.
+#[derive(Debug, PartialEq)]
+pub struct DependencyChange {
+    pub section: Section,
+
+    /// Key in the Cargo.toml section
+    pub name: String,
+
+    /// Crate name, which differs from `name` for renamed dependencies
+    pub package: String,
+
+    pub previous_requirement: Option<String>,
+    pub current_requirement: Option<String>,
+    pub kind: Kind,
+    pub change: Change,
+
+    /// Set for bumps, drops and other changes
+    pub relationship: Option<RangeRelationship>,
+
+    /// Set for bumps, drops and other changes
+    pub bounds: Option<BoundsChange>,
+
+    /// Location of the dependency in the current Cargo.toml, or `None` if it was removed
+    pub current_span: Option<Span>,
+
+    /// Location of the dependency in the previous Cargo.toml, or `None` if it was added
+    pub previous_span: Option<Span>,
+}
+
+impl DependencyChange {
+    /// Git dependencies have no requirement, and show as version `0`
+    pub fn fmt_requirement(requirement: Option<&String>) -> String {
+        match requirement.map(|value| SemverVersion::new(value)) {
+            Some(Ok(version)) => format!("{version}"),
+            Some(Err(_)) => requirement.cloned().unwrap_or_default(),
+            None => String::from("0"),
+        }
+    }
+
+    /// Requirement moved by at least as much as `change`, so a major move is at least minor
+    pub fn is_at_least(&self, change: &Change) -> bool {
+        matches!(self.kind, Kind::Bumped | Kind::Dropped | Kind::Changed)
+            && self.change.rank() <= change.rank()
+    }
+
+    /// New requirement orders below the previous one
+    pub fn is_downgrade(&self) -> bool {
+        self.kind == Kind::Dropped
+    }
+
+    /// Git dependency added, or dependency switched to git, or to another git repository or
+    /// revision
+    pub fn is_git_source(&self) -> bool {
+        match &self.kind {
+            Kind::Added => self.current_requirement.is_none(),
+            Kind::SourceChanged { to, .. } => matches!(to, DependencySource::Git { .. }),
+            _ => false,
+        }
+    }
+
+    /// Dependency skipped, requirement moved by an unknown amount, or either requirement matching
+    /// no version, which CI annotations report as errors
+    fn is_unsatisfiable(&self) -> bool {
+        matches!(self.kind, Kind::Skipped(_))
+            || self.change == Change::Unknown
+            || self.relationship == Some(RangeRelationship::Empty)
+    }
+
+    /// Major upgrade of an existing normal dependency, which conventional commit messages flag as
+    /// a breaking change.  Drops and other major moves are left out, since they do not bring in a
+    /// new major version.
+    fn is_breaking(&self) -> bool {
+        self.section == Section::Dependencies
+            && self.change == Change::Major
+            && self.kind == Kind::Bumped
+    }
+
+    /// Conventional commit summary, for example `bump serde from 1.0.200 to 1.0.215`, or `None`
+    /// for skipped dependencies
+    fn fmt_commit_summary(&self) -> Option<String> {
+        let package = &self.package;
+        let previous = Self::fmt_requirement(self.previous_requirement.as_ref());
+        let current = Self::fmt_requirement(self.current_requirement.as_ref());
+
+        match &self.kind {
+            Kind::Added => Some(format!("add {package} {current}")),
+            Kind::Removed => Some(format!("remove {package} {previous}")),
+            Kind::Bumped | Kind::Dropped | Kind::Changed => Some(format!(
+                "{} {package} from {previous} to {current}",
+                self.kind.direction()
+            )),
+            Kind::NotationOnly => Some(format!(
+                "normalise {package} notation from `{}` to `{}`",
+                self.previous_requirement.as_deref().unwrap_or_default(),
+                self.current_requirement.as_deref().unwrap_or_default()
+            )),
+            Kind::SourceChanged { from, to } => {
+                Some(format!("switch {package} source from {from} to {to}"))
+            }
+            Kind::Skipped(_) => None,
+        }
+    }
+
+    /// Summary followed by the section, for sections other than `[dependencies]`
+    fn fmt_section_summary(&self, summary: &str) -> String {
+        match self.section {
+            Section::Dependencies => String::from(summary),
+            section => format!("{summary} ({section})"),
+        }
+    }
+
+    /// Patch and pre-release moves, and notation-only rewrites, which Markdown reports collapse
+    fn is_lower_severity(&self) -> bool {
+        match self.kind {
+            Kind::Bumped | Kind::Dropped | Kind::Changed => {
+                matches!(self.change, Change::Patch | Change::PreRelease)
+            }
+            Kind::NotationOnly => true,
+            Kind::Added | Kind::Removed | Kind::SourceChanged { .. } | Kind::Skipped(_) => false,
+        }
+    }
+
+    /// Markdown table row, with a trailing newline.  Absent requirements show as a dash.
+    fn fmt_markdown_row(&self) -> String {
+        let crate_name = if self.name == self.package {
+            format!("`{}`", self.package)
+        } else {
+            format!("`{}` (as `{}`)", self.package, self.name)
+        };
+        let requirement = |value: Option<&String>| match value {
+            Some(value) => format!("`{value}`"),
+            None => String::from("—"),
+        };
+        let change = match &self.kind {
+            Kind::Added => String::from("✨ add"),
+            Kind::Removed => String::from("🗑️ remove"),
+            Kind::Bumped | Kind::Dropped | Kind::Changed => format!(
+                "{} {} {}",
+                self.change,
+                self.change.description(),
+                self.kind.direction()
+            ),
+            Kind::NotationOnly => String::from("✏️ notation only"),
+            Kind::SourceChanged { from, to } => format!("🔀 source: {from} → {to}"),
+            Kind::Skipped(reason) => format!("⚠️ skip: {reason}"),
+        };
+
+        format!(
+            "| {crate_name} | {} | {} | {change} |\n",
+            requirement(self.previous_requirement.as_ref()),
+            requirement(self.current_requirement.as_ref())
+        )
+    }
+
+    /// Explains changes which are neither clear bumps nor clear drops.  Bound moves are left out
+    /// where either range is empty, since they mean nothing there.
+    pub fn fmt_detail(&self) -> String {
+        match (&self.relationship, &self.bounds) {
+            (Some(relationship @ RangeRelationship::Empty), _) => format!("{relationship}"),
+            (Some(relationship), Some(bounds))
+                if self.change == Change::Unknown || relationship.ordering().is_none() =>
+            {
+                format!("{relationship}; {bounds}")
+            }
+            (_, Some(bounds)) => format!("{bounds}"),
+            (_, None) => String::new(),
+        }
+    }
+}
+
+/// Emoji text report line, without a trailing newline
+impl fmt::Display for DependencyChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Template::builtin(Style::Emoji).render_change(self))
+    }
+}
+
+/// Version of the JSON output schema, published in `schema/changes.schema.json`.  Bump this
+/// whenever a field is removed or its meaning changes.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Single change in JSON output
+#[derive(Debug, Serialize)]
+struct JsonDependencyChange<'a> {
+    section: String,
+    name: &'a str,
+    package: &'a str,
+    previous_requirement: Option<&'a str>,
+    current_requirement: Option<&'a str>,
+    direction: &'static str,
+    severity: &'static str,
+
+    /// Set for skipped dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<&'a str>,
+
+    /// Set for source changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_source: Option<String>,
+
+    /// Set for source changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_source: Option<String>,
+}
+
+impl<'a> From<&'a DependencyChange> for JsonDependencyChange<'a> {
+    fn from(change: &'a DependencyChange) -> Self {
+        let reason = match &change.kind {
+            Kind::Skipped(reason) => Some(reason.as_str()),
+            _ => None,
+        };
+        let (previous_source, current_source) = match &change.kind {
+            Kind::SourceChanged { from, to } => (Some(from.to_string()), Some(to.to_string())),
+            _ => (None, None),
+        };
+
+        Self {
+            section: change.section.to_string(),
+            name: &change.name,
+            package: &change.package,
+            previous_requirement: change.previous_requirement.as_deref(),
+            current_requirement: change.current_requirement.as_deref(),
+            direction: change.kind.direction(),
+            severity: change.change.description(),
+            reason,
+            previous_source,
+            current_source,
+        }
+    }
+}
+
+/// Cargo.lock supply-chain change in JSON output
+#[derive(Debug, Serialize)]
+struct JsonSupplyChainChange<'a> {
+    package: &'a str,
+    version: &'a str,
+    field: &'static str,
+    previous: Option<&'a str>,
+    current: Option<&'a str>,
+}
+
+impl<'a> From<&'a SupplyChainChange> for JsonSupplyChainChange<'a> {
+    fn from(change: &'a SupplyChainChange) -> Self {
+        Self {
+            package: &change.package,
+            version: &change.version,
+            field: change.field.id(),
+            previous: change.previous.as_deref(),
+            current: change.current.as_deref(),
+        }
+    }
+}
+
+/// Build target change in JSON output
+#[derive(Debug, Serialize)]
+struct JsonTargetChange<'a> {
+    kind: String,
+    name: Option<&'a str>,
+    action: &'static str,
+
+    /// Set for renamed targets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    renamed_to: Option<&'a str>,
+
+    /// Set for field changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<&'static str>,
+
+    /// Set for field changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous: Option<&'a str>,
+
+    /// Set for field changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current: Option<&'a str>,
+}
+
+impl<'a> From<&'a TargetChange> for JsonTargetChange<'a> {
+    fn from(change: &'a TargetChange) -> Self {
+        let mut result = Self {
+            kind: change.kind.to_string(),
+            name: change.name.as_deref(),
+            action: "",
+            renamed_to: None,
+            field: None,
+            previous: None,
+            current: None,
+        };
+        match &change.action {
+            TargetAction::Added => result.action = "add",
+            TargetAction::Removed => result.action = "remove",
+            TargetAction::Renamed(name) => {
+                result.action = "rename";
+                result.renamed_to = Some(name);
+            }
+            TargetAction::FieldChanged {
+                field,
+                previous,
+                current,
+            } => {
+                result.action = "change";
+                result.field = Some(field);
+                result.previous = Some(previous);
+                result.current = Some(current);
+            }
+        }
+
+        result
+    }
+}
+
+/// Unsatisfiable requirement in JSON output
+#[derive(Debug, Serialize)]
+struct JsonUnsatisfiableRequirement<'a> {
+    section: String,
+    package: &'a str,
+    requirement: &'a str,
+    revision: &'a str,
+}
+
+impl<'a> From<&'a UnsatisfiableRequirement> for JsonUnsatisfiableRequirement<'a> {
+    fn from(requirement: &'a UnsatisfiableRequirement) -> Self {
+        Self {
+            section: requirement.section.to_string(),
+            package: &requirement.package,
+            requirement: &requirement.requirement,
+            revision: &requirement.revision,
+        }
+    }
+}
+
+/// Cargo.lock locked version change in JSON output
+#[derive(Debug, Serialize)]
+struct JsonLockedVersionChange<'a> {
+    package: &'a str,
+    previous_requirement: Option<&'a str>,
+    current_requirement: Option<&'a str>,
+    previous_locked: String,
+    current_locked: String,
+}
+
+impl<'a> From<&'a LockedVersionChange> for JsonLockedVersionChange<'a> {
+    fn from(change: &'a LockedVersionChange) -> Self {
+        Self {
+            package: &change.package,
+            previous_requirement: change.previous_requirement.as_deref(),
+            current_requirement: change.current_requirement.as_deref(),
+            previous_locked: change.previous_locked.to_string(),
+            current_locked: change.current_locked.to_string(),
+        }
+    }
+}
+
+/// Cargo.lock package change in JSON output
+#[derive(Debug, Serialize)]
+struct JsonPackageChange<'a> {
+    package: &'a str,
+    previous_version: Option<String>,
+    current_version: Option<String>,
+    direction: &'static str,
+    severity: &'static str,
+    chain: Option<&'a [String]>,
+}
+
+impl<'a> From<&'a PackageChange> for JsonPackageChange<'a> {
+    fn from(change: &'a PackageChange) -> Self {
+        Self {
+            package: &change.package,
+            previous_version: change.previous_version.as_ref().map(ToString::to_string),
+            current_version: change.current_version.as_ref().map(ToString::to_string),
+            direction: change.direction(),
+            severity: change.change.description(),
+            chain: change.chain.as_deref(),
+        }
+    }
+}
+
+/// Cargo.lock duplicate change in JSON output
+#[derive(Debug, Serialize)]
+struct JsonDuplicateChange<'a> {
+    package: &'a str,
+    action: &'static str,
+    versions: Vec<String>,
+    cause: Option<&'a str>,
+}
+
+impl<'a> From<&'a DuplicateChange> for JsonDuplicateChange<'a> {
+    fn from(change: &'a DuplicateChange) -> Self {
+        Self {
+            package: &change.package,
+            action: change.action.id(),
+            versions: change.versions.iter().map(ToString::to_string).collect(),
+            cause: change.cause.as_deref(),
+        }
+    }
+}
+
+/// Top-level JSON output document
+#[derive(Debug, Serialize)]
+struct JsonChangeSet<'a> {
+    schema_version: u32,
+    changes: Vec<JsonDependencyChange<'a>>,
+    target_changes: Vec<JsonTargetChange<'a>>,
+    unsatisfiable_requirements: Vec<JsonUnsatisfiableRequirement<'a>>,
+    locked_version_changes: Vec<JsonLockedVersionChange<'a>>,
+    package_changes: Vec<JsonPackageChange<'a>>,
+    duplicate_changes: Vec<JsonDuplicateChange<'a>>,
+    supply_chain_changes: Vec<JsonSupplyChainChange<'a>>,
+}
+
+/// GitLab Code Quality report entry
+#[derive(Debug, Serialize)]
+struct CodeQualityIssue<'a> {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: CodeQualityLocation<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct CodeQualityLocation<'a> {
+    path: &'a str,
+    lines: CodeQualityLines,
+}
+
+#[derive(Debug, Serialize)]
+struct CodeQualityLines {
+    begin: usize,
+}
+
+/// FNV-1a hash, which, unlike the standard library hasher, is stable across Rust releases, so
+/// GitLab can match issues between pipelines
+fn fingerprint(value: &str) -> String {
+    let hash = value.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+/// Escapes a GitHub workflow command message, or property value when `property` is set
+fn escape_workflow_command(value: &str, property: bool) -> String {
+    let result = value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A");
+    if property {
+        result.replace(':', "%3A").replace(',', "%2C")
+    } else {
+        result
+    }
+}
+
+/// Cargo.lock path used in CI annotations, alongside the Cargo.toml at `cargo_toml_path`
+fn cargo_lock_path(cargo_toml_path: &str) -> String {
+    Path::new(cargo_toml_path)
+        .with_file_name("Cargo.lock")
+        .display()
+        .to_string()
+}
+
+/// Typed record of Cargo.toml dependency and build target changes, and Cargo.lock changes,
+/// which every report format renders from
+#[derive(Debug, Default, PartialEq)]
+pub struct ChangeSet {
+    pub dependencies: Vec<DependencyChange>,
+    pub target_changes: Vec<TargetChange>,
+
+    /// Requirements no version can satisfy, in either Cargo.toml
+    pub unsatisfiable_requirements: Vec<UnsatisfiableRequirement>,
+
+    /// Direct dependency locked version changes, left empty where either Cargo.lock is missing
+    pub locked_version_changes: Vec<LockedVersionChange>,
+
+    /// Package additions, removals and version changes, including transitive dependencies, left
+    /// empty where either Cargo.lock is missing
+    pub package_changes: Vec<PackageChange>,
+
+    /// Crates with several locked versions, left empty where either Cargo.lock is missing
+    pub duplicate_changes: Vec<DuplicateChange>,
+
+    /// Package source and checksum changes, left empty where either Cargo.lock is missing
+    pub supply_chain_changes: Vec<SupplyChainChange>,
+}
+
+impl ChangeSet {
+    /// Text report, with a line for each dependency change, formatted by `template`, followed by
+    /// a line for each build target change.  Set `hide_notation_changes` to skip requirements
+    /// rewritten without changing the versions they match.  Returns an empty string when there
+    /// are no changes, so the caller can decide whether to print `template.no_changes` once
+    /// Cargo.lock changes are known.
+    pub fn print_changes(&self, template: &Template, hide_notation_changes: bool) -> String {
+        let mut result = String::new();
+        for change in &self.dependencies {
+            if hide_notation_changes && change.kind == Kind::NotationOnly {
+                continue;
+            }
+            let _ = writeln!(result, "{}", template.render_change(change));
+        }
+        for change in &self.target_changes {
+            let _ = writeln!(result, "{}", change.fmt_line(template.style));
+        }
+
+        result
+    }
+
+    /// JSON document matching the schema in `schema/changes.schema.json`.  Set
+    /// `hide_notation_changes` to skip requirements rewritten without changing the versions they
+    /// match.
+    pub fn print_json(&self, hide_notation_changes: bool) -> anyhow::Result<String> {
+        let report = JsonChangeSet {
+            schema_version: JSON_SCHEMA_VERSION,
+            changes: self
+                .dependencies
+                .iter()
+                .filter(|change| !(hide_notation_changes && change.kind == Kind::NotationOnly))
+                .map(JsonDependencyChange::from)
+                .collect(),
+            target_changes: self
+                .target_changes
+                .iter()
+                .map(JsonTargetChange::from)
+                .collect(),
+            unsatisfiable_requirements: self
+                .unsatisfiable_requirements
+                .iter()
+                .map(JsonUnsatisfiableRequirement::from)
+                .collect(),
+            locked_version_changes: self
+                .locked_version_changes
+                .iter()
+                .map(JsonLockedVersionChange::from)
+                .collect(),
+            package_changes: self
+                .package_changes
+                .iter()
+                .map(JsonPackageChange::from)
+                .collect(),
+            duplicate_changes: self
+                .duplicate_changes
+                .iter()
+                .map(JsonDuplicateChange::from)
+                .collect(),
+            supply_chain_changes: self
+                .supply_chain_changes
+                .iter()
+                .map(JsonSupplyChainChange::from)
+                .collect(),
+        };
+        let mut result =
+            serde_json::to_string_pretty(&report).context("Serialising changes to JSON")?;
+        result.push('\n');
+
+        Ok(result)
+    }
+
+    /// Markdown report, for pull request descriptions, with a table for each Cargo.toml section.
+    /// Lower-severity changes go in a collapsed details block below the section's main table.
+    pub fn print_markdown(&self, hide_notation_changes: bool) -> String {
+        const TABLE_HEADER: &str = "| Crate | From | To | Change |\n| --- | --- | --- | --- |\n";
+
+        let mut result = String::from("## Dependency changes\n");
+        let mut empty = true;
+        for section in Section::ALL {
+            let (lower_severity, main): (Vec<_>, Vec<_>) = self
+                .dependencies
+                .iter()
+                .filter(|change| change.section == section)
+                .filter(|change| !(hide_notation_changes && change.kind == Kind::NotationOnly))
+                .partition(|change| change.is_lower_severity());
+            if main.is_empty() && lower_severity.is_empty() {
+                continue;
+            }
+            empty = false;
+
+            let _ = write!(result, "\n### `[{section}]`\n");
+            if !main.is_empty() {
+                result.push('\n');
+                result.push_str(TABLE_HEADER);
+                for change in main {
+                    result.push_str(&change.fmt_markdown_row());
+                }
+            }
+            if !lower_severity.is_empty() {
+                let count = lower_severity.len();
+                let plural = if count == 1 { "" } else { "s" };
+                let _ = write!(
+                    result,
+                    "\n<details>\n<summary>{count} lower-severity change{plural}</summary>\n\n\
+                        {TABLE_HEADER}"
+                );
+                for change in lower_severity {
+                    result.push_str(&change.fmt_markdown_row());
+                }
+                result.push_str("\n</details>\n");
+            }
+        }
+
+        if !self.supply_chain_changes.is_empty() {
+            empty = false;
+            result.push_str(
+                "\n### `Cargo.lock` supply chain\n\n| Crate | Version | Change | From | To |\n\
+                    | --- | --- | --- | --- | --- |\n",
+            );
+            for change in &self.supply_chain_changes {
+                let _ = writeln!(
+                    result,
+                    "| `{}` | `{}` | 🛑 change {} | `{}` | `{}` |",
+                    change.package,
+                    change.version,
+                    change.field.id(),
+                    SupplyChainChange::fmt_value(change.previous.as_ref()),
+                    SupplyChainChange::fmt_value(change.current.as_ref())
+                );
+            }
+        }
+
+        if empty {
+            result.push_str("\n🧹 No changes detected.\n");
+        }
+
+        result
+    }
+
+    /// Conventional commit message, for example `build(deps): bump serde from 1.0.200 to
+    /// 1.0.215`.  Changes only to `[dev-dependencies]` use a `chore(dev-deps)` subject, and a
+    /// major bump of a normal dependency marks the message as breaking.  Cargo.lock supply-chain
+    /// changes are listed in the body.  Returns an empty string when there is nothing to commit.
+    pub fn print_commit_message(&self, hide_notation_changes: bool) -> String {
+        let changes: Vec<_> = self
+            .dependencies
+            .iter()
+            .filter(|change| !(hide_notation_changes && change.kind == Kind::NotationOnly))
+            .filter_map(|change| Some((change, change.fmt_commit_summary()?)))
+            .collect();
+        if changes.is_empty() && self.supply_chain_changes.is_empty() {
+            return String::new();
+        }
+
+        let prefix = if self.supply_chain_changes.is_empty()
+            && changes
+                .iter()
+                .all(|(change, _)| change.section == Section::DevDependencies)
+        {
+            "chore(dev-deps)"
+        } else {
+            "build(deps)"
+        };
+        let breaking: Vec<&str> = changes
+            .iter()
+            .filter(|(change, _)| change.is_breaking())
+            .map(|(change, _)| change.package.as_str())
+            .collect();
+        let bang = if breaking.is_empty() { "" } else { "!" };
+
+        let mut result = match changes.as_slice() {
+            [] => format!("{prefix}: change Cargo.lock package sources or checksums\n"),
+            [(_, summary)] => format!("{prefix}{bang}: {summary}\n"),
+            _ => {
+                let mut result =
+                    format!("{prefix}{bang}: update {} dependencies\n\n", changes.len());
+                for (change, summary) in &changes {
+                    let _ = writeln!(result, "- {}", change.fmt_section_summary(summary));
+                }
+                result
+            }
+        };
+        if !self.supply_chain_changes.is_empty() {
+            result.push_str("\nCargo.lock supply-chain changes:\n\n");
+            for change in &self.supply_chain_changes {
+                let _ = writeln!(result, "- {}", change.fmt_summary());
+            }
+        }
+        if !breaking.is_empty() {
+            let _ = writeln!(
+                result,
+                "\nBREAKING CHANGE: new major version of {}",
+                breaking.join(", ")
+            );
+        }
+
+        result
+    }
+
+    /// Changelog entry text for each change, for example `bump serde from 1.0.200 to 1.0.215`.
+    /// Notation-only rewrites and skipped dependencies are left out, since they change nothing
+    /// for users.
+    pub fn changelog_entries(&self) -> Vec<String> {
+        self.dependencies
+            .iter()
+            .filter(|change| change.kind != Kind::NotationOnly)
+            .filter_map(|change| Some(change.fmt_section_summary(&change.fmt_commit_summary()?)))
+            .collect()
+    }
+
+    /// Changes which a report should include
+    fn reported(&self, hide_notation_changes: bool) -> impl Iterator<Item = &DependencyChange> {
+        self.dependencies
+            .iter()
+            .filter(move |change| !(hide_notation_changes && change.kind == Kind::NotationOnly))
+    }
+
+    /// GitHub Actions workflow commands, annotating each change on its line of the Cargo.toml at
+    /// `path`, with messages in `style`.  Unsatisfiable and skipped requirements are errors, and
+    /// breaking changes warnings.  Removed dependencies annotate the file as a whole, as do errors
+    /// for Cargo.lock supply-chain changes.
+    pub fn print_github_annotations(
+        &self,
+        path: &str,
+        hide_notation_changes: bool,
+        style: Style,
+    ) -> String {
+        let template = Template::builtin(style);
+        let mut result = String::new();
+        for change in self.reported(hide_notation_changes) {
+            let level = if change.is_unsatisfiable() {
+                "error"
+            } else if change.is_breaking() {
+                "warning"
+            } else {
+                "notice"
+            };
+            let line = change
+                .current_span
+                .map(|span| format!(",line={},col={}", span.line, span.column))
+                .unwrap_or_default();
+            let _ = writeln!(
+                result,
+                "::{level} file={}{line},title={}::{}",
+                escape_workflow_command(path, true),
+                escape_workflow_command(
+                    &format!("{} {}", change.kind.direction(), change.package),
+                    true
+                ),
+                escape_workflow_command(&template.render_change(change), false)
+            );
+        }
+        for change in &self.supply_chain_changes {
+            let _ = writeln!(
+                result,
+                "::error file={},title={}::{}",
+                escape_workflow_command(&cargo_lock_path(path), true),
+                escape_workflow_command(
+                    &format!("change {} {}", change.field.id(), change.package),
+                    true
+                ),
+                escape_workflow_command(&change.fmt_line(style), false)
+            );
+        }
+
+        result
+    }
+
+    /// GitLab Code Quality report, with an issue for each change on its line of the Cargo.toml
+    /// at `path`, described in `style`.  Severities follow the GitHub annotation levels, so
+    /// unsatisfiable and skipped requirements are critical, and breaking changes major.  Removed
+    /// dependencies are reported on the first line, as are blocker issues for Cargo.lock
+    /// supply-chain changes.
+    pub fn print_gitlab_code_quality(
+        &self,
+        path: &str,
+        hide_notation_changes: bool,
+        style: Style,
+    ) -> anyhow::Result<String> {
+        let template = Template::builtin(style);
+        let lock_path = cargo_lock_path(path);
+        let mut issues: Vec<CodeQualityIssue> = self
+            .reported(hide_notation_changes)
+            .map(|change| {
+                let severity = if change.is_unsatisfiable() {
+                    "critical"
+                } else if change.is_breaking() {
+                    "major"
+                } else if change.is_at_least(&Change::Minor) {
+                    "minor"
+                } else {
+                    "info"
+                };
+                CodeQualityIssue {
+                    description: template.render_change(change),
+                    check_name: format!("dependency-{}", change.kind.direction()),
+                    fingerprint: fingerprint(&format!(
+                        "{}/{}/{}/{}/{}",
+                        change.section,
+                        change.name,
+                        change.kind.direction(),
+                        change.previous_requirement.as_deref().unwrap_or_default(),
+                        change.current_requirement.as_deref().unwrap_or_default()
+                    )),
+                    severity,
+                    location: CodeQualityLocation {
+                        path,
+                        lines: CodeQualityLines {
+                            begin: change.current_span.map_or(1, |span| span.line),
+                        },
+                    },
+                }
+            })
+            .collect();
+        issues.extend(
+            self.supply_chain_changes
+                .iter()
+                .map(|change| CodeQualityIssue {
+                    description: change.fmt_line(style),
+                    check_name: format!("supply-chain-{}", change.field.id()),
+                    fingerprint: fingerprint(&format!(
+                        "Cargo.lock/{}/{}/{}/{}/{}",
+                        change.package,
+                        change.version,
+                        change.field.id(),
+                        change.previous.as_deref().unwrap_or_default(),
+                        change.current.as_deref().unwrap_or_default()
+                    )),
+                    severity: "blocker",
+                    location: CodeQualityLocation {
+                        path: &lock_path,
+                        lines: CodeQualityLines { begin: 1 },
+                    },
+                }),
+        );
+        let mut result = serde_json::to_string_pretty(&issues)
+            .context("Serialising changes to GitLab Code Quality JSON")?;
+        result.push('\n');
+
+        Ok(result)
+    }
+}
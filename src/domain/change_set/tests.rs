@@ -0,0 +1,1200 @@
+use std::cmp::Ordering;
+
+use super::{ChangeSet, DependencyChange, DependencySource, Kind, Section, JSON_SCHEMA_VERSION};
+use crate::domain::{
+    build_target::{Kind as TargetKind, TargetAction, TargetChange},
+    cargo_lock::{
+        DuplicateAction, DuplicateChange, LockedVersionChange, PackageChange, SupplyChainChange,
+        SupplyChainField,
+    },
+    cargo_toml::{Span, UnsatisfiableRequirement},
+    semver::Change,
+    SemverVersion, Style, Template,
+};
+
+fn span(line: usize) -> Span {
+    Span {
+        start: 0,
+        end: 0,
+        line,
+        column: 1,
+    }
+}
+
+fn source_change() -> SupplyChainChange {
+    SupplyChainChange {
+        package: String::from("serde"),
+        version: String::from("1.0.210"),
+        field: SupplyChainField::Source,
+        previous: Some(String::from(
+            "registry+https://github.com/rust-lang/crates.io-index",
+        )),
+        current: Some(String::from("git+https://github.com/example/serde#abc123")),
+    }
+}
+
+fn version(value: &str) -> semver::Version {
+    semver::Version::parse(value).unwrap()
+}
+
+/// Parses `report`, asserting it is valid against `schema/changes.schema.json`
+fn validated_json(report: &str) -> serde_json::Value {
+    let schema: serde_json::Value =
+        serde_json::from_str(include_str!("../../../schema/changes.schema.json")).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+    let result: serde_json::Value = serde_json::from_str(report).unwrap();
+    let errors: Vec<String> = validator
+        .iter_errors(&result)
+        .map(|error| error.to_string())
+        .collect();
+    assert_eq!(errors, Vec::<String>::new());
+
+    result
+}
+
+fn dependency_change(
+    section: Section,
+    previous_requirement: Option<&str>,
+    current_requirement: Option<&str>,
+    kind: Kind,
+) -> DependencyChange {
+    DependencyChange {
+        section,
+        name: String::from("serde"),
+        package: String::from("serde"),
+        previous_requirement: previous_requirement.map(String::from),
+        current_requirement: current_requirement.map(String::from),
+        kind,
+        change: Change::None,
+        relationship: None,
+        bounds: None,
+        current_span: None,
+        previous_span: None,
+    }
+}
+
+#[test]
+fn fmt_section_displays_expected_values() {
+    // act
+    let result = format!(
+        "{} {} {} {}",
+        Section::Dependencies,
+        Section::DevDependencies,
+        Section::BuildDependencies,
+        Section::WorkspaceDependencies
+    );
+
+    // assert
+    assert_eq!(
+        result,
+        String::from("dependencies dev-dependencies build-dependencies workspace.dependencies")
+    );
+}
+
+#[test]
+fn kind_from_ordering_returns_expected_values() {
+    // assert
+    assert_eq!(Kind::from_ordering(Some(Ordering::Greater)), Kind::Bumped);
+    assert_eq!(Kind::from_ordering(Some(Ordering::Less)), Kind::Dropped);
+    assert_eq!(
+        Kind::from_ordering(Some(Ordering::Equal)),
+        Kind::NotationOnly
+    );
+    assert_eq!(Kind::from_ordering(None), Kind::Changed);
+}
+
+#[test]
+fn fmt_dependency_change_displays_expected_values() {
+    // arrange
+    let current = SemverVersion::new(">=1.0.150, <1.0.220").unwrap();
+    let previous = SemverVersion::new(">=1.0.100, <1.0.200").unwrap();
+    let changed = DependencyChange {
+        change: Change::Patch,
+        relationship: Some(current.relationship(&previous).unwrap()),
+        bounds: Some(current.bounds_change(&previous).unwrap()),
+        ..dependency_change(
+            Section::DevDependencies,
+            Some(">=1.0.100, <1.0.200"),
+            Some(">=1.0.150, <1.0.220"),
+            Kind::Changed,
+        )
+    };
+
+    // act
+    let added = format!(
+        "{}",
+        dependency_change(Section::Dependencies, None, Some("1.0"), Kind::Added)
+    );
+    let removed = format!(
+        "{}",
+        dependency_change(Section::BuildDependencies, None, None, Kind::Removed)
+    );
+    let notation = format!(
+        "{}",
+        dependency_change(
+            Section::WorkspaceDependencies,
+            Some("1"),
+            Some("^1.0"),
+            Kind::NotationOnly
+        )
+    );
+    let skipped = format!(
+        "{}",
+        dependency_change(
+            Section::DevDependencies,
+            None,
+            Some("x"),
+            Kind::Skipped(String::from("Unexpected semver"))
+        )
+    );
+    let changed = format!("{changed}");
+
+    // assert
+    assert_eq!(added, String::from("✨ add serde 1.0"));
+    assert_eq!(
+        removed,
+        String::from("🗑\u{fe0f} remove serde (🧱 build-dependencies) 0")
+    );
+    assert_eq!(
+        notation,
+        String::from(
+            "✏\u{fe0f} normalise notation serde (🗄\u{fe0f} workspace-dependencies) from `1` to \
+                `^1.0`"
+        )
+    );
+    assert_eq!(
+        skipped,
+        String::from("⚠\u{fe0f} skip serde [dev-dependencies]: Unexpected semver")
+    );
+    assert_eq!(
+        changed,
+        String::from(
+            "🔧 change serde (🖥\u{fe0f} dev-dependencies) from >=1.0.100, <1.0.200 to \
+                >=1.0.150, <1.0.220 (shifted up; floor raised patch, ceiling raised patch)"
+        )
+    );
+}
+
+#[test]
+fn fmt_detail_leaves_out_bound_moves_for_empty_ranges() {
+    // arrange
+    let current = SemverVersion::new(">=1.2, <1.1").unwrap();
+    let previous = SemverVersion::new("1.0").unwrap();
+    let change = DependencyChange {
+        change: Change::Unknown,
+        relationship: Some(current.relationship(&previous).unwrap()),
+        bounds: Some(current.bounds_change(&previous).unwrap()),
+        ..dependency_change(
+            Section::Dependencies,
+            Some("1.0"),
+            Some(">=1.2, <1.1"),
+            Kind::Changed,
+        )
+    };
+
+    // act
+    let result = change.fmt_detail();
+
+    // assert
+    assert_eq!(result, String::from("empty range"));
+}
+
+#[test]
+fn print_changes_hides_notation_only_changes_when_requested() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![
+            dependency_change(
+                Section::Dependencies,
+                Some("1"),
+                Some("1.0"),
+                Kind::NotationOnly,
+            ),
+            dependency_change(Section::Dependencies, None, Some("1.0"), Kind::Added),
+        ],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let shown = change_set.print_changes(Template::builtin(Style::Emoji), false);
+    let hidden = change_set.print_changes(Template::builtin(Style::Emoji), true);
+
+    // assert
+    assert_eq!(
+        shown,
+        String::from("✏\u{fe0f} normalise notation serde from `1` to `1.0`\n✨ add serde 1.0\n")
+    );
+    assert_eq!(hidden, String::from("✨ add serde 1.0\n"));
+}
+
+#[test]
+fn print_json_returns_expected_document() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![
+            DependencyChange {
+                change: Change::Minor,
+                ..dependency_change(
+                    Section::DevDependencies,
+                    Some("1.0"),
+                    Some("1.1"),
+                    Kind::Bumped,
+                )
+            },
+            dependency_change(
+                Section::Dependencies,
+                Some("1"),
+                Some("1.0"),
+                Kind::NotationOnly,
+            ),
+            DependencyChange {
+                change: Change::Unknown,
+                ..dependency_change(
+                    Section::BuildDependencies,
+                    None,
+                    Some("x"),
+                    Kind::Skipped(String::from("Unexpected semver")),
+                )
+            },
+        ],
+        supply_chain_changes: vec![source_change()],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = change_set.print_json(true).unwrap();
+    validated_json(&result);
+
+    // assert
+    assert_eq!(
+        result,
+        String::from(
+            r#"{
+  "schema_version": 1,
+  "changes": [
+    {
+      "section": "dev-dependencies",
+      "name": "serde",
+      "package": "serde",
+      "previous_requirement": "1.0",
+      "current_requirement": "1.1",
+      "direction": "bump",
+      "severity": "minor"
+    },
+    {
+      "section": "build-dependencies",
+      "name": "serde",
+      "package": "serde",
+      "previous_requirement": null,
+      "current_requirement": "x",
+      "direction": "skip",
+      "severity": "unknown",
+      "reason": "Unexpected semver"
+    }
+  ],
+  "target_changes": [],
+  "unsatisfiable_requirements": [],
+  "locked_version_changes": [],
+  "package_changes": [],
+  "duplicate_changes": [],
+  "supply_chain_changes": [
+    {
+      "package": "serde",
+      "version": "1.0.210",
+      "field": "source",
+      "previous": "registry+https://github.com/rust-lang/crates.io-index",
+      "current": "git+https://github.com/example/serde#abc123"
+    }
+  ]
+}
+"#
+        )
+    );
+}
+
+#[test]
+fn json_schema_matches_output_values() {
+    // arrange
+    let schema: serde_json::Value =
+        serde_json::from_str(include_str!("../../../schema/changes.schema.json")).unwrap();
+    let definitions = &schema["$defs"];
+    let kinds = [
+        Kind::Added,
+        Kind::Removed,
+        Kind::Bumped,
+        Kind::Dropped,
+        Kind::Changed,
+        Kind::NotationOnly,
+        Kind::SourceChanged {
+            from: DependencySource::Registry,
+            to: DependencySource::Path(String::new()),
+        },
+        Kind::Skipped(String::new()),
+    ];
+    let changes = [
+        Change::Major,
+        Change::Minor,
+        Change::Patch,
+        Change::PreRelease,
+        Change::None,
+        Change::Unknown,
+    ];
+    let sections = [
+        Section::Dependencies,
+        Section::DevDependencies,
+        Section::BuildDependencies,
+        Section::WorkspaceDependencies,
+    ];
+
+    // assert
+    assert_eq!(
+        schema["properties"]["schema_version"]["const"],
+        JSON_SCHEMA_VERSION
+    );
+    for kind in &kinds {
+        assert!(definitions["change"]["properties"]["direction"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&kind.direction().into()));
+    }
+    for severity in &changes {
+        assert!(definitions["severity"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&severity.description().into()));
+    }
+    for section in &sections {
+        assert!(definitions["section"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&section.to_string().into()));
+    }
+    for field in [SupplyChainField::Source, SupplyChainField::Checksum] {
+        assert!(
+            definitions["supply_chain_change"]["properties"]["field"]["enum"]
+                .as_array()
+                .unwrap()
+                .contains(&field.id().into())
+        );
+    }
+    for kind in [
+        TargetKind::Lib,
+        TargetKind::Bin,
+        TargetKind::Example,
+        TargetKind::Test,
+        TargetKind::Bench,
+    ] {
+        assert!(definitions["target_change"]["properties"]["kind"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&kind.to_string().into()));
+    }
+    for action in [
+        DuplicateAction::Introduce,
+        DuplicateAction::Extend,
+        DuplicateAction::Keep,
+        DuplicateAction::Resolve,
+    ] {
+        assert!(
+            definitions["duplicate_change"]["properties"]["action"]["enum"]
+                .as_array()
+                .unwrap()
+                .contains(&action.id().into())
+        );
+    }
+}
+
+#[test]
+fn print_json_includes_target_changes() {
+    // arrange
+    let change_set = ChangeSet {
+        target_changes: vec![
+            TargetChange {
+                kind: TargetKind::Lib,
+                name: None,
+                action: TargetAction::Renamed(String::from("core_lib")),
+            },
+            TargetChange {
+                kind: TargetKind::Bin,
+                name: Some(String::from("helper")),
+                action: TargetAction::Added,
+            },
+            TargetChange {
+                kind: TargetKind::Bench,
+                name: Some(String::from("parse")),
+                action: TargetAction::FieldChanged {
+                    field: "harness",
+                    previous: String::from("default"),
+                    current: String::from("false"),
+                },
+            },
+        ],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = validated_json(&change_set.print_json(false).unwrap());
+
+    // assert
+    assert_eq!(
+        result["target_changes"],
+        serde_json::json!([
+            { "kind": "lib", "name": null, "action": "rename", "renamed_to": "core_lib" },
+            { "kind": "bin", "name": "helper", "action": "add" },
+            {
+                "kind": "bench",
+                "name": "parse",
+                "action": "change",
+                "field": "harness",
+                "previous": "default",
+                "current": "false"
+            }
+        ])
+    );
+}
+
+#[test]
+fn print_json_includes_unsatisfiable_requirements() {
+    // arrange
+    let change_set = ChangeSet {
+        unsatisfiable_requirements: vec![UnsatisfiableRequirement {
+            section: Section::BuildDependencies,
+            package: String::from("cc"),
+            requirement: String::from(">1.1.0, <1.1.1"),
+            revision: String::from("committed"),
+        }],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = validated_json(&change_set.print_json(false).unwrap());
+
+    // assert
+    assert_eq!(
+        result["unsatisfiable_requirements"],
+        serde_json::json!([{
+            "section": "build-dependencies",
+            "package": "cc",
+            "requirement": ">1.1.0, <1.1.1",
+            "revision": "committed"
+        }])
+    );
+}
+
+#[test]
+fn print_json_includes_locked_version_changes() {
+    // arrange
+    let change_set = ChangeSet {
+        locked_version_changes: vec![LockedVersionChange {
+            package: String::from("serde"),
+            previous_requirement: Some(String::from("1.0.200")),
+            current_requirement: Some(String::from("1.0.215")),
+            previous_locked: version("1.0.210"),
+            current_locked: version("1.0.217"),
+        }],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = validated_json(&change_set.print_json(false).unwrap());
+
+    // assert
+    assert_eq!(
+        result["locked_version_changes"],
+        serde_json::json!([{
+            "package": "serde",
+            "previous_requirement": "1.0.200",
+            "current_requirement": "1.0.215",
+            "previous_locked": "1.0.210",
+            "current_locked": "1.0.217"
+        }])
+    );
+}
+
+#[test]
+fn print_json_includes_package_changes_with_chains() {
+    // arrange
+    let change_set = ChangeSet {
+        package_changes: vec![
+            PackageChange {
+                package: String::from("atomic-waker"),
+                previous_version: None,
+                current_version: Some(version("0.3.1")),
+                change: Change::None,
+                chain: Some(vec![String::from("reqwest"), String::from("hyper")]),
+            },
+            PackageChange {
+                package: String::from("reqwest"),
+                previous_version: Some(version("0.12.12")),
+                current_version: Some(version("0.12.9")),
+                change: Change::Minor,
+                chain: Some(Vec::new()),
+            },
+            PackageChange {
+                package: String::from("want"),
+                previous_version: Some(version("0.3.1")),
+                current_version: None,
+                change: Change::None,
+                chain: None,
+            },
+        ],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = validated_json(&change_set.print_json(false).unwrap());
+
+    // assert
+    assert_eq!(
+        result["package_changes"],
+        serde_json::json!([
+            {
+                "package": "atomic-waker",
+                "previous_version": null,
+                "current_version": "0.3.1",
+                "direction": "add",
+                "severity": "none",
+                "chain": ["reqwest", "hyper"]
+            },
+            {
+                "package": "reqwest",
+                "previous_version": "0.12.12",
+                "current_version": "0.12.9",
+                "direction": "drop",
+                "severity": "minor",
+                "chain": []
+            },
+            {
+                "package": "want",
+                "previous_version": "0.3.1",
+                "current_version": null,
+                "direction": "remove",
+                "severity": "none",
+                "chain": null
+            }
+        ])
+    );
+}
+
+#[test]
+fn print_json_includes_duplicate_changes() {
+    // arrange
+    let change_set = ChangeSet {
+        duplicate_changes: vec![
+            DuplicateChange {
+                package: String::from("h2"),
+                action: DuplicateAction::Introduce,
+                versions: vec![version("0.3.26"), version("0.4.6")],
+                cause: Some(String::from("reqwest")),
+            },
+            DuplicateChange {
+                package: String::from("log"),
+                action: DuplicateAction::Resolve,
+                versions: vec![version("0.3.9"), version("0.4.22")],
+                cause: None,
+            },
+        ],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = validated_json(&change_set.print_json(false).unwrap());
+
+    // assert
+    assert_eq!(
+        result["duplicate_changes"],
+        serde_json::json!([
+            {
+                "package": "h2",
+                "action": "introduce",
+                "versions": ["0.3.26", "0.4.6"],
+                "cause": "reqwest"
+            },
+            {
+                "package": "log",
+                "action": "resolve",
+                "versions": ["0.3.9", "0.4.22"],
+                "cause": null
+            }
+        ])
+    );
+}
+
+#[test]
+fn print_markdown_returns_table_for_each_section() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![
+            DependencyChange {
+                change: Change::Major,
+                ..dependency_change(
+                    Section::Dependencies,
+                    Some("1.0"),
+                    Some("2.0"),
+                    Kind::Bumped,
+                )
+            },
+            DependencyChange {
+                change: Change::Patch,
+                ..dependency_change(
+                    Section::Dependencies,
+                    Some("1.0.1"),
+                    Some("1.0.2"),
+                    Kind::Bumped,
+                )
+            },
+            DependencyChange {
+                name: String::from("serde_renamed"),
+                ..dependency_change(Section::DevDependencies, None, Some("1.0"), Kind::Added)
+            },
+            dependency_change(
+                Section::WorkspaceDependencies,
+                Some("1"),
+                Some("1.0"),
+                Kind::NotationOnly,
+            ),
+        ],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = change_set.print_markdown(false);
+
+    // assert
+    assert_eq!(
+        result,
+        String::from(
+            "## Dependency changes
+
+### `[dependencies]`
+
+| Crate | From | To | Change |
+| --- | --- | --- | --- |
+| `serde` | `1.0` | `2.0` | ❗ major bump |
+
+<details>
+<summary>1 lower-severity change</summary>
+
+| Crate | From | To | Change |
+| --- | --- | --- | --- |
+| `serde` | `1.0.1` | `1.0.2` | 🔧 patch bump |
+
+</details>
+
+### `[dev-dependencies]`
+
+| Crate | From | To | Change |
+| --- | --- | --- | --- |
+| `serde` (as `serde_renamed`) | — | `1.0` | ✨ add |
+
+### `[workspace.dependencies]`
+
+<details>
+<summary>1 lower-severity change</summary>
+
+| Crate | From | To | Change |
+| --- | --- | --- | --- |
+| `serde` | `1` | `1.0` | ✏\u{fe0f} notation only |
+
+</details>
+"
+        )
+    );
+}
+
+#[test]
+fn print_markdown_reports_no_changes() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![dependency_change(
+            Section::Dependencies,
+            Some("1"),
+            Some("1.0"),
+            Kind::NotationOnly,
+        )],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = change_set.print_markdown(true);
+
+    // assert
+    assert_eq!(
+        result,
+        String::from("## Dependency changes\n\n🧹 No changes detected.\n")
+    );
+}
+
+#[test]
+fn print_commit_message_returns_expected_subject_for_single_change() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![DependencyChange {
+            change: Change::Patch,
+            ..dependency_change(
+                Section::Dependencies,
+                Some("1.0.200"),
+                Some("1.0.215"),
+                Kind::Bumped,
+            )
+        }],
+        ..ChangeSet::default()
+    };
+    let dev_change_set = ChangeSet {
+        dependencies: vec![dependency_change(
+            Section::DevDependencies,
+            None,
+            Some("1.0"),
+            Kind::Added,
+        )],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = change_set.print_commit_message(false);
+    let dev_result = dev_change_set.print_commit_message(false);
+    let empty_result = ChangeSet::default().print_commit_message(false);
+
+    // assert
+    assert_eq!(
+        result,
+        String::from("build(deps): bump serde from 1.0.200 to 1.0.215\n")
+    );
+    assert_eq!(dev_result, String::from("chore(dev-deps): add serde 1.0\n"));
+    assert_eq!(empty_result, String::new());
+}
+
+#[test]
+fn print_commit_message_flags_breaking_changes() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![
+            DependencyChange {
+                change: Change::Major,
+                ..dependency_change(
+                    Section::Dependencies,
+                    Some("1.0"),
+                    Some("2.0"),
+                    Kind::Bumped,
+                )
+            },
+            DependencyChange {
+                change: Change::Major,
+                ..dependency_change(
+                    Section::DevDependencies,
+                    Some("1.0"),
+                    Some("2.0"),
+                    Kind::Bumped,
+                )
+            },
+            dependency_change(
+                Section::Dependencies,
+                None,
+                Some("x"),
+                Kind::Skipped(String::from("Unexpected semver")),
+            ),
+        ],
+        ..ChangeSet::default()
+    };
+    let non_breaking_change_set = ChangeSet {
+        dependencies: vec![
+            DependencyChange {
+                change: Change::Major,
+                ..dependency_change(
+                    Section::Dependencies,
+                    Some("2.0"),
+                    Some("1.0"),
+                    Kind::Dropped,
+                )
+            },
+            DependencyChange {
+                change: Change::Major,
+                ..dependency_change(
+                    Section::Dependencies,
+                    Some(">=1.0, <2.0"),
+                    Some(">=0.9, <3.0"),
+                    Kind::Changed,
+                )
+            },
+        ],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = change_set.print_commit_message(false);
+    let non_breaking_result = non_breaking_change_set.print_commit_message(false);
+
+    // assert
+    assert_eq!(
+        result,
+        String::from(
+            "build(deps)!: update 2 dependencies
+
+- bump serde from 1.0 to 2.0
+- bump serde from 1.0 to 2.0 (dev-dependencies)
+
+BREAKING CHANGE: new major version of serde
+"
+        )
+    );
+    assert_eq!(
+        non_breaking_result,
+        String::from(
+            "build(deps): update 2 dependencies
+
+- drop serde from 2.0 to 1.0
+- change serde from >=1.0, <2.0 to >=0.9, <3.0
+"
+        )
+    );
+}
+
+#[test]
+fn policy_predicates_return_expected_values() {
+    // arrange
+    let minor_bump = DependencyChange {
+        change: Change::Minor,
+        ..dependency_change(
+            Section::Dependencies,
+            Some("1.0"),
+            Some("1.1"),
+            Kind::Bumped,
+        )
+    };
+    let patch_drop = DependencyChange {
+        change: Change::Patch,
+        ..dependency_change(
+            Section::Dependencies,
+            Some("1.0.2"),
+            Some("1.0.1"),
+            Kind::Dropped,
+        )
+    };
+    let git_added = dependency_change(Section::Dependencies, None, None, Kind::Added);
+    let git_removed = dependency_change(Section::Dependencies, None, None, Kind::Removed);
+    let registry_added = dependency_change(Section::Dependencies, None, Some("1.0"), Kind::Added);
+
+    // assert
+    assert!(!minor_bump.is_at_least(&Change::Major));
+    assert!(minor_bump.is_at_least(&Change::Minor));
+    assert!(minor_bump.is_at_least(&Change::Patch));
+    assert!(!minor_bump.is_downgrade());
+    assert!(!patch_drop.is_at_least(&Change::Minor));
+    assert!(patch_drop.is_downgrade());
+    assert!(!registry_added.is_at_least(&Change::Patch));
+    assert!(git_added.is_git_source());
+    assert!(!git_removed.is_git_source());
+    assert!(!registry_added.is_git_source());
+}
+
+#[test]
+fn changelog_entries_leaves_out_notation_only_and_skipped_changes() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![
+            dependency_change(
+                Section::Dependencies,
+                Some("1"),
+                Some("1.0"),
+                Kind::NotationOnly,
+            ),
+            dependency_change(Section::BuildDependencies, None, Some("1.0"), Kind::Added),
+            dependency_change(
+                Section::Dependencies,
+                None,
+                Some("x"),
+                Kind::Skipped(String::from("Unexpected semver")),
+            ),
+        ],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = change_set.changelog_entries();
+
+    // assert
+    assert_eq!(
+        result,
+        vec![String::from("add serde 1.0 (build-dependencies)")]
+    );
+}
+
+/// Breaking bump, major dev-dependency bump, removal, skipped requirement and unsatisfiable
+/// requirement, in that order
+fn annotated_change_set() -> ChangeSet {
+    let current = SemverVersion::new(">=2.0, <1.0").unwrap();
+    let previous = SemverVersion::new("1.0").unwrap();
+
+    ChangeSet {
+        dependencies: vec![
+            DependencyChange {
+                change: Change::Major,
+                current_span: Some(span(12)),
+                ..dependency_change(
+                    Section::Dependencies,
+                    Some("1.0"),
+                    Some("2.0"),
+                    Kind::Bumped,
+                )
+            },
+            DependencyChange {
+                change: Change::Major,
+                current_span: Some(span(20)),
+                ..dependency_change(
+                    Section::DevDependencies,
+                    Some("1.0"),
+                    Some("2.0"),
+                    Kind::Bumped,
+                )
+            },
+            dependency_change(Section::Dependencies, Some("1.0"), None, Kind::Removed),
+            DependencyChange {
+                change: Change::Unknown,
+                current_span: Some(span(3)),
+                ..dependency_change(
+                    Section::Dependencies,
+                    None,
+                    Some("x"),
+                    Kind::Skipped(String::from("Unexpected semver: 100%")),
+                )
+            },
+            DependencyChange {
+                change: Change::Major,
+                current_span: Some(span(5)),
+                relationship: Some(current.relationship(&previous).unwrap()),
+                bounds: Some(current.bounds_change(&previous).unwrap()),
+                ..dependency_change(
+                    Section::Dependencies,
+                    Some("1.0"),
+                    Some(">=2.0, <1.0"),
+                    Kind::Changed,
+                )
+            },
+        ],
+        ..ChangeSet::default()
+    }
+}
+
+#[test]
+fn print_github_annotations_returns_workflow_commands() {
+    // arrange
+    let change_set = annotated_change_set();
+
+    // act
+    let result = change_set.print_github_annotations("Cargo.toml", false, Style::Emoji);
+    let plain_result = change_set.print_github_annotations("Cargo.toml", false, Style::Plain);
+
+    // assert
+    assert_eq!(
+        result,
+        String::from(
+            "::warning file=Cargo.toml,line=12,col=1,title=bump serde::❗ bump serde from 1.0 to 2.0 ()
+::notice file=Cargo.toml,line=20,col=1,title=bump serde::❗ bump serde (🖥\u{fe0f} \
+                dev-dependencies) from 1.0 to 2.0 ()
+::notice file=Cargo.toml,title=remove serde::🗑\u{fe0f} remove serde 1.0
+::error file=Cargo.toml,line=3,col=1,title=skip serde::⚠\u{fe0f} skip serde [dependencies]: \
+                Unexpected semver: 100%25
+::error file=Cargo.toml,line=5,col=1,title=change serde::❗ change serde from 1.0 to >=2.0, <1.0 \
+                (empty range)
+"
+        )
+    );
+    assert_eq!(
+        plain_result,
+        String::from(
+            "::warning file=Cargo.toml,line=12,col=1,title=bump serde::MAJOR bump serde from 1.0 to 2.0 ()
+::notice file=Cargo.toml,line=20,col=1,title=bump serde::MAJOR bump serde (dev-dependencies) \
+                from 1.0 to 2.0 ()
+::notice file=Cargo.toml,title=remove serde::REMOVE serde 1.0
+::error file=Cargo.toml,line=3,col=1,title=skip serde::SKIP serde [dependencies]: Unexpected \
+                semver: 100%25
+::error file=Cargo.toml,line=5,col=1,title=change serde::MAJOR change serde from 1.0 to >=2.0, \
+                <1.0 (empty range)
+"
+        )
+    );
+}
+
+#[test]
+fn print_gitlab_code_quality_returns_issue_for_each_change() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![DependencyChange {
+            change: Change::Minor,
+            current_span: Some(span(7)),
+            ..dependency_change(
+                Section::DevDependencies,
+                Some("1.0"),
+                Some("1.1"),
+                Kind::Bumped,
+            )
+        }],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let result = change_set
+        .print_gitlab_code_quality("Cargo.toml", false, Style::Emoji)
+        .unwrap();
+    let plain_result = change_set
+        .print_gitlab_code_quality("Cargo.toml", false, Style::Plain)
+        .unwrap();
+
+    // assert
+    let issues: serde_json::Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(
+        issues,
+        serde_json::json!([{
+            "description": "📦 bump serde (🖥\u{fe0f} dev-dependencies) from 1.0 to 1.1 ()",
+            "check_name": "dependency-bump",
+            "fingerprint": "9cb115a1893d26ca",
+            "severity": "minor",
+            "location": { "path": "Cargo.toml", "lines": { "begin": 7 } }
+        }])
+    );
+    let plain_issues: serde_json::Value = serde_json::from_str(&plain_result).unwrap();
+    assert_eq!(
+        plain_issues[0]["description"],
+        "MINOR bump serde (dev-dependencies) from 1.0 to 1.1 ()"
+    );
+}
+
+#[test]
+fn print_gitlab_code_quality_matches_github_annotation_levels() {
+    // arrange
+    let change_set = annotated_change_set();
+
+    // act
+    let result = change_set
+        .print_gitlab_code_quality("Cargo.toml", false, Style::Emoji)
+        .unwrap();
+
+    // assert
+    let issues: serde_json::Value = serde_json::from_str(&result).unwrap();
+    let severities: Vec<&str> = issues
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["severity"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        severities,
+        vec!["major", "minor", "info", "critical", "critical"]
+    );
+}
+
+#[test]
+fn supply_chain_changes_appear_in_every_format() {
+    // arrange
+    let change_set = ChangeSet {
+        supply_chain_changes: vec![source_change()],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let markdown = change_set.print_markdown(false);
+    let commit_message = change_set.print_commit_message(false);
+    let github = change_set.print_github_annotations("crates/app/Cargo.toml", false, Style::Emoji);
+    let gitlab = change_set
+        .print_gitlab_code_quality("Cargo.toml", false, Style::Emoji)
+        .unwrap();
+
+    // assert
+    assert_eq!(
+        markdown,
+        String::from(
+            "## Dependency changes
+
+### `Cargo.lock` supply chain
+
+| Crate | Version | Change | From | To |
+| --- | --- | --- | --- | --- |
+| `serde` | `1.0.210` | 🛑 change source | \
+                `registry+https://github.com/rust-lang/crates.io-index` | \
+                `git+https://github.com/example/serde#abc123` |
+"
+        )
+    );
+    assert_eq!(
+        commit_message,
+        String::from(
+            "build(deps): change Cargo.lock package sources or checksums
+
+Cargo.lock supply-chain changes:
+
+- change source serde 1.0.210 from registry+https://github.com/rust-lang/crates.io-index to \
+                git+https://github.com/example/serde#abc123
+"
+        )
+    );
+    assert_eq!(
+        github,
+        String::from(
+            "::error file=crates/app/Cargo.lock,title=change source serde::🛑 change source serde \
+                (🔒 Cargo.lock) 1.0.210 from registry+https://github.com/rust-lang/crates.io-index \
+                to git+https://github.com/example/serde#abc123
+"
+        )
+    );
+    let issues: serde_json::Value = serde_json::from_str(&gitlab).unwrap();
+    assert_eq!(issues[0]["check_name"], "supply-chain-source");
+    assert_eq!(issues[0]["severity"], "blocker");
+    assert_eq!(
+        issues[0]["location"],
+        serde_json::json!({ "path": "Cargo.lock", "lines": { "begin": 1 } })
+    );
+}
+
+#[test]
+fn source_changes_appear_in_every_format() {
+    // arrange
+    let change_set = ChangeSet {
+        dependencies: vec![dependency_change(
+            Section::Dependencies,
+            Some("1.0.210"),
+            None,
+            Kind::SourceChanged {
+                from: DependencySource::Registry,
+                to: DependencySource::Git {
+                    url: String::from("https://github.com/serde-rs/serde"),
+                    reference: Some(String::from("rev abc123")),
+                },
+            },
+        )],
+        ..ChangeSet::default()
+    };
+
+    // act
+    let text = change_set.print_changes(Template::builtin(Style::Emoji), false);
+    let json: serde_json::Value =
+        serde_json::from_str(&change_set.print_json(false).unwrap()).unwrap();
+    let markdown = change_set.print_markdown(false);
+    let commit_message = change_set.print_commit_message(false);
+
+    // assert
+    assert_eq!(
+        text,
+        String::from(
+            "🔀 switch source serde from registry to git https://github.com/serde-rs/serde (rev \
+                abc123)\n"
+        )
+    );
+    assert_eq!(json["changes"][0]["direction"], "source");
+    assert_eq!(json["changes"][0]["severity"], "none");
+    assert_eq!(json["changes"][0]["previous_source"], "registry");
+    assert_eq!(
+        json["changes"][0]["current_source"],
+        "git https://github.com/serde-rs/serde (rev abc123)"
+    );
+    assert!(markdown.contains(
+        "| `serde` | `1.0.210` | — | 🔀 source: registry → git https://github.com/serde-rs/serde \
+            (rev abc123) |\n"
+    ));
+    assert_eq!(
+        commit_message,
+        String::from(
+            "build(deps): switch serde source from registry to git \
+                https://github.com/serde-rs/serde (rev abc123)\n"
+        )
+    );
+}
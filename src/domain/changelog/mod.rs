@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests;
+
+const UNRELEASED_HEADING: &str = "## [Unreleased]";
+const DEPENDENCIES_HEADING: &str = "### Dependencies";
+
+fn is_release_heading(line: &str) -> bool {
+    line.starts_with("## ")
+}
+
+fn is_unreleased_heading(line: &str) -> bool {
+    is_release_heading(line) && line.to_lowercase().contains("[unreleased]")
+}
+
+fn is_subsection_heading(line: &str) -> bool {
+    line.starts_with("### ")
+}
+
+/// Index just after the last non-blank line in `lines[start..end]`, or `start` if they are all
+/// blank
+fn end_of_content(lines: &[String], start: usize, end: usize) -> usize {
+    (start..end)
+        .rev()
+        .find(|index| !lines[*index].trim().is_empty())
+        .map_or(start, |index| index + 1)
+}
+
+/// Inserts `block` at `index`, keeping a blank line either side of it
+fn insert_block(lines: &mut Vec<String>, index: usize, mut block: Vec<String>) {
+    if index > 0 && !lines[index - 1].trim().is_empty() {
+        block.insert(0, String::new());
+    }
+    if lines.get(index).is_some_and(|line| !line.trim().is_empty()) {
+        block.push(String::new());
+    }
+    lines.splice(index..index, block);
+}
+
+/// Adds `entries` as list items under a `### Dependencies` subheading of the `## [Unreleased]`
+/// section of a Keep a Changelog file, creating the section and subheading where missing.
+/// Entries already listed under the subheading are skipped, so the changelog can be updated on
+/// every commit.  Returns the updated changelog and the number of entries added.
+pub fn add_dependency_entries(changelog: &str, entries: &[String]) -> (String, usize) {
+    let mut lines: Vec<String> = changelog.lines().map(String::from).collect();
+
+    let unreleased_start = lines.iter().position(|line| is_unreleased_heading(line));
+    let dependencies_start = unreleased_start.and_then(|start| {
+        lines[start + 1..]
+            .iter()
+            .take_while(|line| !is_release_heading(line))
+            .position(|line| line.trim() == DEPENDENCIES_HEADING)
+            .map(|offset| start + 1 + offset)
+    });
+    let existing: Vec<&str> = dependencies_start
+        .map(|start| {
+            lines[start + 1..]
+                .iter()
+                .take_while(|line| !is_release_heading(line) && !is_subsection_heading(line))
+                .map(|line| line.trim())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut new_items: Vec<String> = Vec::new();
+    for entry in entries {
+        let item = format!("- {entry}");
+        if !existing.contains(&item.as_str()) && !new_items.contains(&item) {
+            new_items.push(item);
+        }
+    }
+    let added = new_items.len();
+    if added == 0 {
+        return (String::from(changelog), 0);
+    }
+
+    match (unreleased_start, dependencies_start) {
+        (_, Some(start)) => {
+            let end = lines[start + 1..]
+                .iter()
+                .position(|line| is_release_heading(line) || is_subsection_heading(line))
+                .map_or(lines.len(), |offset| start + 1 + offset);
+            let index = end_of_content(&lines, start + 1, end);
+            if index == start + 1 {
+                insert_block(&mut lines, index, new_items);
+            } else {
+                // Extend the existing list, rather than starting a new one
+                let mut block = new_items;
+                if lines.get(index).is_some_and(|line| !line.trim().is_empty()) {
+                    block.push(String::new());
+                }
+                lines.splice(index..index, block);
+            }
+        }
+        (Some(start), None) => {
+            let end = lines[start + 1..]
+                .iter()
+                .position(|line| is_release_heading(line))
+                .map_or(lines.len(), |offset| start + 1 + offset);
+            let index = end_of_content(&lines, start + 1, end);
+            let mut block = vec![String::from(DEPENDENCIES_HEADING), String::new()];
+            block.extend(new_items);
+            insert_block(&mut lines, index, block);
+        }
+        (None, None) => {
+            let index = lines
+                .iter()
+                .position(|line| is_release_heading(line))
+                .unwrap_or(lines.len());
+            let mut block = vec![
+                String::from(UNRELEASED_HEADING),
+                String::new(),
+                String::from(DEPENDENCIES_HEADING),
+                String::new(),
+            ];
+            block.extend(new_items);
+            insert_block(&mut lines, index, block);
+        }
+    }
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+
+    (result, added)
+}
@@ -0,0 +1,156 @@
+use super::add_dependency_entries;
+
+fn entries() -> Vec<String> {
+    vec![
+        String::from("bump serde from 1.0.200 to 1.0.215"),
+        String::from("add insta 1.41 (dev-dependencies)"),
+    ]
+}
+
+#[test]
+fn add_dependency_entries_creates_unreleased_section() {
+    // arrange
+    let changelog = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- First release
+";
+
+    // act
+    let (result, added) = add_dependency_entries(changelog, &entries());
+
+    // assert
+    assert_eq!(added, 2);
+    assert_eq!(
+        result,
+        String::from(
+            "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+## [Unreleased]
+
+### Dependencies
+
+- bump serde from 1.0.200 to 1.0.215
+- add insta 1.41 (dev-dependencies)
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- First release
+"
+        )
+    );
+}
+
+#[test]
+fn add_dependency_entries_creates_dependencies_subheading() {
+    // arrange
+    let changelog = "# Changelog
+
+## [Unreleased]
+
+### Fixed
+
+- Crash on empty Cargo.toml
+
+## [0.1.0] - 2026-01-01
+";
+
+    // act
+    let (result, added) = add_dependency_entries(changelog, &entries()[..1]);
+
+    // assert
+    assert_eq!(added, 1);
+    assert_eq!(
+        result,
+        String::from(
+            "# Changelog
+
+## [Unreleased]
+
+### Fixed
+
+- Crash on empty Cargo.toml
+
+### Dependencies
+
+- bump serde from 1.0.200 to 1.0.215
+
+## [0.1.0] - 2026-01-01
+"
+        )
+    );
+}
+
+#[test]
+fn add_dependency_entries_skips_entries_already_present() {
+    // arrange
+    let changelog = "# Changelog
+
+## [Unreleased]
+
+### Dependencies
+
+- bump serde from 1.0.200 to 1.0.215
+### Fixed
+
+- Crash on empty Cargo.toml
+";
+
+    // act
+    let (result, added) = add_dependency_entries(changelog, &entries());
+    let (rerun_result, rerun_added) = add_dependency_entries(&result, &entries());
+
+    // assert
+    assert_eq!(added, 1);
+    assert_eq!(
+        result,
+        String::from(
+            "# Changelog
+
+## [Unreleased]
+
+### Dependencies
+
+- bump serde from 1.0.200 to 1.0.215
+- add insta 1.41 (dev-dependencies)
+
+### Fixed
+
+- Crash on empty Cargo.toml
+"
+        )
+    );
+    assert_eq!(rerun_added, 0);
+    assert_eq!(rerun_result, result);
+}
+
+#[test]
+fn add_dependency_entries_appends_unreleased_section_when_there_are_no_releases() {
+    // act
+    let (result, added) = add_dependency_entries("# Changelog\n", &entries()[..1]);
+
+    // assert
+    assert_eq!(added, 1);
+    assert_eq!(
+        result,
+        String::from(
+            "# Changelog
+
+## [Unreleased]
+
+### Dependencies
+
+- bump serde from 1.0.200 to 1.0.215
+"
+        )
+    );
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use super::cargo_toml::Span;
+
+/// Stable code for each kind of Cargo.toml error, shown as `error[RCD001]`.  Codes are never
+/// reused, so they are safe to match in scripts and to search for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Code {
+    /// Cargo.toml is not valid TOML
+    InvalidToml,
+
+    /// A value outside the dependency tables has an unexpected type
+    UnexpectedValue,
+
+    /// Dependency inherited from the workspace, with `workspace = true`
+    InheritedDependency,
+
+    /// Path dependency with no `version` requirement
+    PathDependency,
+
+    /// Any other dependency entry which is neither a requirement string, nor a table with
+    /// `version` or `git`
+    UnsupportedDependency,
+}
+
+impl Code {
+    pub fn id(self) -> &'static str {
+        match self {
+            Code::InvalidToml => "RCD001",
+            Code::UnexpectedValue => "RCD002",
+            Code::InheritedDependency => "RCD003",
+            Code::PathDependency => "RCD004",
+            Code::UnsupportedDependency => "RCD005",
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// Error in a source file, displayed in the style of rustc, with the offending line underlined
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub code: Code,
+    pub message: String,
+
+    /// File name, or path, shown in the location line
+    path: String,
+
+    /// Git revision the file came from, for example `HEAD` or `working tree`
+    revision: Option<String>,
+
+    span: Span,
+
+    /// Full text of the first line of `span`
+    source_line: String,
+
+    hint: Option<String>,
+}
+
+impl Diagnostic {
+    /// `span` locates the error in `source`
+    pub fn new(code: Code, message: &str, source: &str, span: Span) -> Self {
+        let line_start = source[..span.start]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let source_line = source[line_start..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches('\r');
+
+        Self {
+            code,
+            message: String::from(message),
+            path: String::from("Cargo.toml"),
+            revision: None,
+            span,
+            source_line: String::from(source_line),
+            hint: None,
+        }
+    }
+
+    /// Names the file and revision shown in the location line
+    #[must_use]
+    pub fn with_origin(mut self, path: &str, revision: Option<&str>) -> Self {
+        self.path = String::from(path);
+        self.revision = revision.map(String::from);
+
+        self
+    }
+
+    #[must_use]
+    pub fn with_hint(mut self, hint: &str) -> Self {
+        self.hint = Some(String::from(hint));
+
+        self
+    }
+
+    /// Number of carets, covering the span up to the end of its first line, and at least one
+    fn underline_length(&self) -> usize {
+        let start = self.source_line.len().min(
+            self.source_line
+                .char_indices()
+                .nth(self.span.column - 1)
+                .map_or(self.source_line.len(), |(index, _)| index),
+        );
+        let end = self
+            .source_line
+            .len()
+            .min(start + self.span.end.saturating_sub(self.span.start));
+
+        self.source_line[start..end].chars().count().max(1)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line_number = self.span.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let revision = self
+            .revision
+            .as_ref()
+            .map(|value| format!(" ({value})"))
+            .unwrap_or_default();
+
+        writeln!(f, "error[{}]: {}", self.code, self.message)?;
+        writeln!(
+            f,
+            "{gutter}--> {}:{}:{}{revision}",
+            self.path, self.span.line, self.span.column
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_number} | {}", self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.span.column - 1),
+            "^".repeat(self.underline_length())
+        )?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n{gutter} |\n{gutter} = help: {hint}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostic {}
@@ -0,0 +1,105 @@
+use super::{Code, Diagnostic};
+use crate::domain::cargo_toml::Span;
+
+const SOURCE: &str =
+    "[package]\nname = \"example\"\n\n[dependencies]\nserde = { workspace = true }\n";
+
+#[test]
+fn code_ids_are_stable() {
+    // act
+    let result: Vec<&str> = [
+        Code::InvalidToml,
+        Code::UnexpectedValue,
+        Code::InheritedDependency,
+        Code::PathDependency,
+        Code::UnsupportedDependency,
+    ]
+    .into_iter()
+    .map(Code::id)
+    .collect();
+
+    // assert
+    assert_eq!(
+        result,
+        vec!["RCD001", "RCD002", "RCD003", "RCD004", "RCD005"]
+    );
+}
+
+#[test]
+fn diagnostic_displays_snippet_with_caret_revision_and_hint() {
+    // arrange
+    let span = Span {
+        start: 43,
+        end: 71,
+        line: 5,
+        column: 1,
+    };
+
+    // act
+    let result = Diagnostic::new(
+        Code::InheritedDependency,
+        "inherited dependency `serde` in `[dependencies]`",
+        SOURCE,
+        span,
+    )
+    .with_origin("Cargo.toml", Some("HEAD"))
+    .with_hint("inherited `workspace = true` entries not supported");
+
+    // assert
+    assert_eq!(
+        result.to_string(),
+        String::from(
+            "error[RCD003]: inherited dependency `serde` in `[dependencies]`
+ --> Cargo.toml:5:1 (HEAD)
+  |
+5 | serde = { workspace = true }
+  | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+  |
+  = help: inherited `workspace = true` entries not supported"
+        )
+    );
+}
+
+#[test]
+fn diagnostic_underlines_at_least_one_character_within_the_line() {
+    // arrange
+    let empty = Span {
+        start: 51,
+        end: 51,
+        line: 5,
+        column: 9,
+    };
+    let multiline = Span {
+        start: 0,
+        end: 26,
+        line: 1,
+        column: 1,
+    };
+
+    // act
+    let empty_result = Diagnostic::new(Code::InvalidToml, "expected `}`", SOURCE, empty);
+    let multiline_result =
+        Diagnostic::new(Code::UnexpectedValue, "invalid type", SOURCE, multiline);
+
+    // assert
+    assert_eq!(
+        empty_result.to_string(),
+        String::from(
+            "error[RCD001]: expected `}`
+ --> Cargo.toml:5:9
+  |
+5 | serde = { workspace = true }
+  |         ^"
+        )
+    );
+    assert_eq!(
+        multiline_result.to_string(),
+        String::from(
+            "error[RCD002]: invalid type
+ --> Cargo.toml:1:1
+  |
+1 | [package]
+  | ^^^^^^^^^"
+        )
+    );
+}
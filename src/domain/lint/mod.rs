@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Write as _},
+};
+
+use super::{
+    change_set::Section,
+    style::{Mark, Style},
+    CargoLockFile, CargoTomlFile, SemverVersion,
+};
+
+/// Risky version requirement patterns.  Rule IDs are stable, so they are safe to reference from
+/// CI configuration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rule {
+    /// `*`
+    Wildcard,
+
+    /// No upper bound, for example `>=1.2`
+    Unbounded,
+
+    /// `=` comparator
+    ExactPin,
+
+    /// `~0` or `~0.0.x` comparator, which, unlike `^`, matches breaking releases
+    TildeOnZeroMajor,
+
+    /// Lower bound below the version in Cargo.lock
+    FloorBelowLock,
+}
+
+impl Rule {
+    pub fn id(self) -> &'static str {
+        match self {
+            Rule::Wildcard => "wildcard",
+            Rule::Unbounded => "unbounded",
+            Rule::ExactPin => "exact-pin",
+            Rule::TildeOnZeroMajor => "tilde-zero-major",
+            Rule::FloorBelowLock => "floor-below-lock",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Finding {
+    pub rule: Rule,
+    pub package: String,
+    pub section: Section,
+    pub requirement: String,
+    message: String,
+}
+
+impl Finding {
+    /// Report line, without a trailing newline
+    pub fn fmt_line(&self, style: Style) -> String {
+        let Finding {
+            rule,
+            package,
+            section,
+            requirement,
+            message,
+        } = self;
+
+        format!(
+            "{} {rule} {package} [{section}] requirement `{requirement}`: {message}",
+            style.mark(Mark::Lint)
+        )
+    }
+}
+
+/// Emoji report line, without a trailing newline
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.fmt_line(Style::Emoji))
+    }
+}
+
+/// Checks a single requirement against every rule.  Requirements which cannot be parsed or
+/// compared are left for the change report to flag.
+fn lint_requirement(
+    section: Section,
+    package: &str,
+    requirement: &str,
+    lock: Option<&CargoLockFile>,
+) -> Vec<Finding> {
+    let Ok(version) = SemverVersion::new(requirement) else {
+        return Vec::new();
+    };
+    let finding = |rule, message: String| Finding {
+        rule,
+        package: String::from(package),
+        section,
+        requirement: String::from(requirement),
+        message,
+    };
+
+    if version.is_wildcard() {
+        return vec![finding(Rule::Wildcard, String::from("matches any version"))];
+    }
+
+    let mut result = Vec::new();
+    if let Ok(true) = version.is_unbounded() {
+        result.push(finding(
+            Rule::Unbounded,
+            String::from("no upper bound, so future breaking releases match"),
+        ));
+    }
+    if version.is_exact_pin() {
+        result.push(finding(
+            Rule::ExactPin,
+            String::from("exact pin blocks compatible updates"),
+        ));
+    }
+    if version.is_tilde_on_zero_major() {
+        result.push(finding(
+            Rule::TildeOnZeroMajor,
+            String::from("`~0` and `~0.0` allow breaking updates"),
+        ));
+    }
+    if let (Some(locked), Ok(lower_bound)) = (
+        lock.and_then(|value| value.locked_version(package, Some(requirement))),
+        version.lower_bound(),
+    ) {
+        // Build metadata is irrelevant to precedence
+        let locked = semver::Version {
+            build: semver::BuildMetadata::EMPTY,
+            ..locked
+        };
+        if lower_bound < locked {
+            result.push(finding(
+                Rule::FloorBelowLock,
+                format!("lower bound {lower_bound} is below locked version {locked}"),
+            ));
+        }
+    }
+
+    result
+}
+
+/// Lints every dependency in `manifest`, using `lock`, if available, for locked versions
+pub fn lint_manifest(manifest: &CargoTomlFile, lock: Option<&CargoLockFile>) -> Vec<Finding> {
+    manifest
+        .section_requirements()
+        .into_iter()
+        .filter_map(|(section, _, dependency)| {
+            let requirement = dependency.requirement?;
+            Some(lint_requirement(
+                section,
+                dependency.package,
+                requirement,
+                lock,
+            ))
+        })
+        .flatten()
+        .collect()
+}
+
+/// Lints only dependencies added, or with a changed requirement, since the `previous` manifest
+pub fn lint_changed(
+    manifest: &CargoTomlFile,
+    previous: &CargoTomlFile,
+    lock: Option<&CargoLockFile>,
+) -> Vec<Finding> {
+    let previous_requirements: BTreeSet<_> = previous
+        .section_requirements()
+        .into_iter()
+        .map(|(section, key, dependency)| (section, key, dependency.requirement))
+        .collect();
+
+    manifest
+        .section_requirements()
+        .into_iter()
+        .filter(|(section, key, dependency)| {
+            !previous_requirements.contains(&(*section, *key, dependency.requirement))
+        })
+        .filter_map(|(section, _, dependency)| {
+            let requirement = dependency.requirement?;
+            Some(lint_requirement(
+                section,
+                dependency.package,
+                requirement,
+                lock,
+            ))
+        })
+        .flatten()
+        .collect()
+}
+
+pub fn print_findings(findings: &[Finding], style: Style) -> String {
+    let mut result = String::new();
+    for finding in findings {
+        let _ = writeln!(result, "{}", finding.fmt_line(style));
+    }
+
+    result
+}
@@ -0,0 +1,156 @@
+use crate::domain::{CargoLockFile, CargoTomlFile, Style};
+
+use super::{lint_changed, lint_manifest, print_findings, Rule};
+
+const CARGO_LOCK: &str = r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 4
+
+[[package]]
+name = "anyhow"
+version = "1.0.95"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "34ac096ce696dc2fcabef30516bb13c0a68a11d30131d3df6f04711467681b04"
+
+[[package]]
+name = "log"
+version = "0.4.22"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "a7a70ba024b9dc04c27ea2f0c0548feb474ec5c54bba33a7f72f873a39d07b24"
+"#;
+
+#[test]
+fn fmt_rule_displays_expected_values() {
+    // act
+    let result = format!(
+        "{} {} {} {} {}",
+        Rule::Wildcard,
+        Rule::Unbounded,
+        Rule::ExactPin,
+        Rule::TildeOnZeroMajor,
+        Rule::FloorBelowLock
+    );
+
+    // assert
+    assert_eq!(
+        result,
+        String::from("wildcard unbounded exact-pin tilde-zero-major floor-below-lock")
+    );
+}
+
+#[test]
+fn lint_manifest_flags_risky_requirements() {
+    // arrange
+    let cargo_toml = CargoTomlFile::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0"
+glob = "*"
+log = "0.4.22"
+serde = { version = "=1.0.200", package = "serde-core" }
+
+[dev-dependencies]
+proptest = ">=1.4"
+tokio-test = "~0.4.4"
+trycmd = "~0.0.8"
+
+[build-dependencies]
+cc = { git = "https://github.com/rust-lang/cc-rs" }
+"#,
+    )
+    .unwrap();
+    let cargo_lock = CargoLockFile::new_from_str(CARGO_LOCK).unwrap();
+
+    // act
+    let findings = lint_manifest(&cargo_toml, Some(&cargo_lock));
+
+    // assert
+    assert_eq!(
+        print_findings(&findings, Style::Emoji),
+        String::from(
+            "🔍 lint floor-below-lock anyhow [dependencies] requirement `1.0`: lower bound 1.0.0 \
+                is below locked version 1.0.95\n\
+                🔍 lint wildcard glob [dependencies] requirement `*`: matches any version\n\
+                🔍 lint exact-pin serde-core [dependencies] requirement `=1.0.200`: exact pin \
+                blocks compatible updates\n\
+                🔍 lint unbounded proptest [dev-dependencies] requirement `>=1.4`: no upper \
+                bound, so future breaking releases match\n\
+                🔍 lint tilde-zero-major trycmd [dev-dependencies] requirement `~0.0.8`: `~0` \
+                and `~0.0` allow breaking updates\n"
+        )
+    );
+}
+
+#[test]
+fn lint_manifest_skips_lock_checks_without_lock_file() {
+    // arrange
+    let cargo_toml = CargoTomlFile::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0"
+"#,
+    )
+    .unwrap();
+
+    // act
+    let findings = lint_manifest(&cargo_toml, None);
+
+    // assert
+    assert_eq!(findings, Vec::new());
+}
+
+#[test]
+fn lint_changed_only_flags_added_and_changed_requirements() {
+    // arrange
+    let cargo_toml = CargoTomlFile::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0"
+glob = "*"
+log = "=0.4.22"
+
+[dev-dependencies]
+proptest = ">=1.4"
+"#,
+    )
+    .unwrap();
+    let previous_cargo_toml = CargoTomlFile::new_from_str(
+        r#"[package]
+name = "some-example-crate"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0"
+glob = "*"
+log = "0.4.22"
+"#,
+    )
+    .unwrap();
+    let cargo_lock = CargoLockFile::new_from_str(CARGO_LOCK).unwrap();
+
+    // act
+    let findings = lint_changed(&cargo_toml, &previous_cargo_toml, Some(&cargo_lock));
+
+    // assert
+    let rules: Vec<(Rule, &str)> = findings
+        .iter()
+        .map(|value| (value.rule, value.package.as_str()))
+        .collect();
+    assert_eq!(
+        rules,
+        vec![(Rule::ExactPin, "log"), (Rule::Unbounded, "proptest")]
+    );
+}
@@ -1,7 +1,20 @@
+pub mod build_target;
+pub mod cargo_lock;
 pub mod cargo_toml;
+pub mod change_set;
+pub mod changelog;
+pub mod diagnostic;
+pub mod lint;
 pub mod repo;
 pub mod semver;
+pub mod style;
+pub mod template;
 
+pub use build_target::{Target as BuildTarget, Targets as BuildTargets};
+pub use cargo_lock::File as CargoLockFile;
 pub use cargo_toml::File as CargoTomlFile;
+pub use change_set::ChangeSet;
 pub use repo::Repo;
 pub use semver::Version as SemverVersion;
+pub use style::Style;
+pub use template::Template;
@@ -1,6 +1,6 @@
 use std::{fmt, io::Write, path::Path};
 
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use git2::Repository;
 
 pub struct Repo {
@@ -39,29 +39,50 @@ impl Repo {
             .with_context(|| format!("Failed to open repo: `{}`", local_path.as_ref().display()))
     }
 
-    pub fn get_committed_cargo_toml(&self, buffer: &mut Vec<u8>) -> anyhow::Result<()> {
+    /// Copies a file in the root directory of the committed tree at `HEAD` into the buffer.
+    /// Returns `false` when no file with that name is committed.
+    fn get_committed_root_file(
+        &self,
+        file_name: &str,
+        buffer: &mut Vec<u8>,
+    ) -> anyhow::Result<bool> {
         let main_tree = self
             .repository
             .revparse_single("HEAD^{tree}")
             .context( "Unable to access git repo branch head.  Is the project within an existing git repo?") ?
             .peel_to_tree()
             .context("Get repo default branch tree")?;
-        let file_entry = main_tree
-            .iter()
-            .find(|val| val.name() == Ok("Cargo.toml"))
-            .context("No Cargo.toml found in route directory of Git branch")?;
+        let Some(file_entry) = main_tree.iter().find(|val| val.name() == Ok(file_name)) else {
+            return Ok(false);
+        };
 
         let file_object = file_entry
             .to_object(&self.repository)
-            .context("Convert Cargo.toml file entry to object")?;
+            .with_context(|| format!("Convert {file_name} file entry to object"))?;
         let file_blob = file_object
             .as_blob()
-            .context("Convert Cargo.toml file entry to blob")?;
+            .with_context(|| format!("Convert {file_name} file entry to blob"))?;
         buffer
             .write_all(file_blob.content())
-            .context("Copy Cargo.toml content to temporary buffer")?;
+            .with_context(|| format!("Copy {file_name} content to temporary buffer"))?;
 
-        Ok(())
+        Ok(true)
+    }
+
+    pub fn get_committed_cargo_toml(&self, buffer: &mut Vec<u8>) -> anyhow::Result<()> {
+        if self.get_committed_root_file("Cargo.toml", buffer)? {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "No Cargo.toml found in route directory of Git branch"
+            ))
+        }
+    }
+
+    /// Returns `false`, leaving the buffer untouched, when no Cargo.lock is committed (common for
+    /// library crates)
+    pub fn get_committed_cargo_lock(&self, buffer: &mut Vec<u8>) -> anyhow::Result<bool> {
+        self.get_committed_root_file("Cargo.lock", buffer)
     }
 }
 
@@ -71,7 +92,13 @@ mod tests {
 
     use git2::Repository;
 
-    use crate::{domain::Repo, test_helpers::create_temporary_repo_with_committed_file};
+    use crate::{
+        domain::Repo,
+        test_helpers::{
+            create_temporary_repo_with_committed_cargo_toml_and_lock,
+            create_temporary_repo_with_committed_file,
+        },
+    };
 
     #[test]
     fn new_outputs_error_if_repo_does_not_exist() {
@@ -176,4 +203,48 @@ mod tests {
             fs::read_to_string("src/domain/repo/test_fixtures/cargo_toml_repo.toml").unwrap();
         assert_eq!(std::str::from_utf8(&result).unwrap(), initial_cargo_toml);
     }
+
+    #[test]
+    fn get_committed_cargo_lock_retrieves_expected_file() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+
+        let (repo_path, _cargo_toml_path, _cargo_lock_path) =
+            create_temporary_repo_with_committed_cargo_toml_and_lock(
+                &temp_dir,
+                "src/domain/repo/test_fixtures/cargo_toml_repo.toml",
+                "src/domain/repo/test_fixtures/cargo_lock_repo.lock",
+            );
+        let repo = Repo::new(&repo_path).unwrap();
+
+        // act
+        let mut result: Vec<u8> = Vec::new();
+        let outcome = repo.get_committed_cargo_lock(&mut result).unwrap();
+
+        // assert
+        assert!(outcome);
+        let initial_cargo_lock =
+            fs::read_to_string("src/domain/repo/test_fixtures/cargo_lock_repo.lock").unwrap();
+        assert_eq!(std::str::from_utf8(&result).unwrap(), initial_cargo_lock);
+    }
+
+    #[test]
+    fn get_committed_cargo_lock_returns_false_when_no_lock_is_committed() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+
+        let (repo_path, _cargo_toml_path) = create_temporary_repo_with_committed_file(
+            &temp_dir,
+            "src/domain/repo/test_fixtures/cargo_toml_repo.toml",
+        );
+        let repo = Repo::new(&repo_path).unwrap();
+
+        // act
+        let mut result: Vec<u8> = Vec::new();
+        let outcome = repo.get_committed_cargo_lock(&mut result).unwrap();
+
+        // assert
+        assert!(!outcome);
+        assert!(result.is_empty());
+    }
 }
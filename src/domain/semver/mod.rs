@@ -15,11 +15,12 @@ mod tests;
 /// - `*`, `1.*`, `1.2.*`;
 /// - `>= 1.2.3`, `> 1.2.3`, `< 1.2.3`, `= 1.2.3`; and
 /// - `>= 1.2, <1.5` (multiple version requirements for single dependency).
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Change {
     Major,
     Minor,
     Patch,
+    PreRelease,
     None,
     Unknown,
 }
@@ -30,6 +31,7 @@ impl fmt::Display for Change {
             Change::Major => "❗",
             Change::Minor => "📦",
             Change::Patch => "🔧",
+            Change::PreRelease => "🧪",
             Change::None => "😐",
             Change::Unknown => "🤷",
         };
@@ -37,6 +39,228 @@ impl fmt::Display for Change {
     }
 }
 
+impl Change {
+    /// Lower values are more significant
+    pub fn rank(&self) -> u8 {
+        match self {
+            Change::Major => 0,
+            Change::Minor => 1,
+            Change::Patch => 2,
+            Change::PreRelease => 3,
+            Change::None => 4,
+            Change::Unknown => 5,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Change::Major => "major",
+            Change::Minor => "minor",
+            Change::Patch => "patch",
+            Change::PreRelease => "pre-release",
+            Change::None => "none",
+            Change::Unknown => "unknown",
+        }
+    }
+}
+
+/// Reasons the range of versions matching a requirement cannot be computed
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// Operator added to the `semver` crate after this tool was written
+    UnsupportedOperator(String),
+
+    /// Version component too large to compute the end of the range
+    Overflow { component: &'static str, value: u64 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnsupportedOperator(op) => {
+                write!(formatter, "Unsupported semver operator: `{op}`")
+            }
+            Error::Overflow { component, value } => {
+                write!(
+                    formatter,
+                    "Unexpectedly high {component} version: `{value}`"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Relationship between the range of versions matching a new requirement and the range matching
+/// the previous one
+#[derive(Debug, PartialEq)]
+pub enum RangeRelationship {
+    Unchanged,
+
+    /// Same matching versions, written differently, for example `1` and `1.0`
+    NotationOnly,
+
+    /// New range contains the previous one; flags record which bounds moved
+    Widened {
+        lower: bool,
+        upper: bool,
+    },
+
+    /// Previous range contains the new one; flags record which bounds moved
+    Narrowed {
+        lower: bool,
+        upper: bool,
+    },
+
+    /// Ranges overlap, with both bounds moved up
+    ShiftedUp,
+
+    /// Ranges overlap, with both bounds moved down
+    ShiftedDown,
+
+    DisjointAbove,
+    DisjointBelow,
+
+    /// Either requirement matches no versions
+    Empty,
+}
+
+impl RangeRelationship {
+    /// Orders the new requirement relative to the previous one, where that is unambiguous.  For
+    /// example, widening a range at both ends is neither a bump nor a drop.
+    pub fn ordering(&self) -> Option<Ordering> {
+        match self {
+            RangeRelationship::Unchanged | RangeRelationship::NotationOnly => Some(Ordering::Equal),
+            RangeRelationship::DisjointAbove
+            | RangeRelationship::Widened {
+                lower: false,
+                upper: true,
+            }
+            | RangeRelationship::Narrowed {
+                lower: true,
+                upper: false,
+            } => Some(Ordering::Greater),
+            RangeRelationship::DisjointBelow
+            | RangeRelationship::Widened {
+                lower: true,
+                upper: false,
+            }
+            | RangeRelationship::Narrowed {
+                lower: false,
+                upper: true,
+            } => Some(Ordering::Less),
+            RangeRelationship::Widened { .. }
+            | RangeRelationship::Narrowed { .. }
+            | RangeRelationship::ShiftedUp
+            | RangeRelationship::ShiftedDown
+            | RangeRelationship::Empty => None,
+        }
+    }
+}
+
+impl fmt::Display for RangeRelationship {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            RangeRelationship::Unchanged => "unchanged",
+            RangeRelationship::NotationOnly => "notation only",
+            RangeRelationship::Widened { .. } => "widened",
+            RangeRelationship::Narrowed { .. } => "narrowed",
+            RangeRelationship::ShiftedUp => "shifted up",
+            RangeRelationship::ShiftedDown => "shifted down",
+            RangeRelationship::DisjointAbove => "disjoint, above previous range",
+            RangeRelationship::DisjointBelow => "disjoint, below previous range",
+            RangeRelationship::Empty => "empty range",
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// Direction a range bound moved in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoundMove {
+    Unchanged,
+    Raised,
+    Lowered,
+
+    /// Upper bound only: previous range was unbounded above
+    Added,
+
+    /// Upper bound only: new range is unbounded above
+    Removed,
+}
+
+impl BoundMove {
+    fn new(bound: &semver::Version, other_bound: &semver::Version) -> Self {
+        match bound.cmp(other_bound) {
+            Ordering::Less => BoundMove::Lowered,
+            Ordering::Equal => BoundMove::Unchanged,
+            Ordering::Greater => BoundMove::Raised,
+        }
+    }
+
+    fn new_upper(bound: &semver::Version, other_bound: &semver::Version) -> Self {
+        let unbounded = semver::Version::new(u64::MAX, u64::MAX, u64::MAX);
+        match (*bound == unbounded, *other_bound == unbounded) {
+            (true, false) => BoundMove::Removed,
+            (false, true) => BoundMove::Added,
+            _ => Self::new(bound, other_bound),
+        }
+    }
+}
+
+/// Moves in the floor (minimum acceptable version) and ceiling of a requirement's range of
+/// matching versions.  For a library, a raised floor forces downstream users to upgrade, while a
+/// raised ceiling only allows newer versions.
+#[derive(Debug, PartialEq)]
+pub struct BoundsChange {
+    pub lower: Change,
+    pub upper: Change,
+    pub floor: BoundMove,
+    pub ceiling: BoundMove,
+
+    /// Either requirement matches no versions at all, so bound moves are meaningless
+    empty: bool,
+}
+
+impl BoundsChange {
+    /// Most significant of the two bound moves
+    pub fn change_type(&self) -> Change {
+        if self.empty {
+            return Change::Unknown;
+        }
+        if self.lower.rank() <= self.upper.rank() {
+            self.lower.clone()
+        } else {
+            self.upper.clone()
+        }
+    }
+}
+
+/// Lists the bounds which moved, for example `floor raised minor, ceiling raised major`
+impl fmt::Display for BoundsChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let floor = match self.floor {
+            BoundMove::Raised => Some(format!("floor raised {}", self.lower.description())),
+            BoundMove::Lowered => Some(format!("floor lowered {}", self.lower.description())),
+            BoundMove::Unchanged | BoundMove::Added | BoundMove::Removed => None,
+        };
+        let ceiling = match self.ceiling {
+            BoundMove::Raised => Some(format!("ceiling raised {}", self.upper.description())),
+            BoundMove::Lowered => Some(format!("ceiling lowered {}", self.upper.description())),
+            BoundMove::Added => Some(String::from("ceiling added")),
+            BoundMove::Removed => Some(String::from("ceiling removed")),
+            BoundMove::Unchanged => None,
+        };
+        match (floor, ceiling) {
+            (None, None) => write!(f, "bounds unchanged"),
+            (Some(floor), None) => write!(f, "{floor}"),
+            (None, Some(ceiling)) => write!(f, "{ceiling}"),
+            (Some(floor), Some(ceiling)) => write!(f, "{floor}, {ceiling}"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Version {
     req: VersionReq,
@@ -59,9 +283,10 @@ impl fmt::Display for Version {
     }
 }
 
+/// Requirements with ranges that cannot be computed (see [`Error`]) are not comparable
 impl PartialOrd for Version {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Version::range_compare(&self.comparator_ranges(), &other.comparator_ranges())
+        self.relationship(other).ok()?.ordering()
     }
 }
 
@@ -82,61 +307,131 @@ impl Version {
         Ok(Self { req })
     }
 
-    fn range_compare(a: &Range<semver::Version>, b: &Range<semver::Version>) -> Option<Ordering> {
+    /// Describes range `a` relative to range `b`
+    fn range_relationship(
+        a: &Range<semver::Version>,
+        b: &Range<semver::Version>,
+    ) -> RangeRelationship {
         if a == b {
-            Some(Ordering::Equal)
-        } else if a.end <= b.start
-            || (a.start < b.start && a.end == b.end)
-            || (a.start == b.start && a.end < b.end)
-        {
-            Some(Ordering::Less)
-        } else if a.start >= b.end
-            || (a.start > b.start && a.end == b.end)
-            || (a.start == b.start && a.end > b.end)
-        {
-            Some(Ordering::Greater)
+            RangeRelationship::Unchanged
+        } else if a.is_empty() || b.is_empty() {
+            RangeRelationship::Empty
+        } else if a.end <= b.start {
+            RangeRelationship::DisjointBelow
+        } else if a.start >= b.end {
+            RangeRelationship::DisjointAbove
+        } else if a.start <= b.start && a.end >= b.end {
+            RangeRelationship::Widened {
+                lower: a.start < b.start,
+                upper: a.end > b.end,
+            }
+        } else if a.start >= b.start && a.end <= b.end {
+            RangeRelationship::Narrowed {
+                lower: a.start > b.start,
+                upper: a.end < b.end,
+            }
+        } else if a.start > b.start {
+            RangeRelationship::ShiftedUp
         } else {
-            None
+            RangeRelationship::ShiftedDown
         }
     }
 
-    fn version_with_bumped_major(major: u64) -> semver::Version {
-        semver::Version {
-            major: major
-                .checked_add(1)
-                .unwrap_or_else(|| panic!("Unexpectedly high major version: `{major}`")),
+    /// Describes how the range of versions matching this requirement relates to the range
+    /// matching the `other` requirement
+    pub fn relationship(&self, other: &Self) -> Result<RangeRelationship, Error> {
+        let relationship =
+            Self::range_relationship(&self.comparator_ranges()?, &other.comparator_ranges()?);
+
+        Ok(match relationship {
+            RangeRelationship::Unchanged if self.req != other.req => {
+                RangeRelationship::NotationOnly
+            }
+            relationship => relationship,
+        })
+    }
+
+    fn version_with_bumped_major(major: u64) -> Result<semver::Version, Error> {
+        Ok(semver::Version {
+            major: major.checked_add(1).ok_or(Error::Overflow {
+                component: "major",
+                value: major,
+            })?,
             minor: 0,
             patch: 0,
             pre: Prerelease::EMPTY,
             build: BuildMetadata::EMPTY,
-        }
+        })
     }
 
-    fn version_with_bumped_minor(major: u64, minor: u64) -> semver::Version {
-        semver::Version {
+    fn version_with_bumped_minor(major: u64, minor: u64) -> Result<semver::Version, Error> {
+        Ok(semver::Version {
             major,
-            minor: minor
-                .checked_add(1)
-                .unwrap_or_else(|| panic!("Unexpectedly high minor version: `{minor}`")),
+            minor: minor.checked_add(1).ok_or(Error::Overflow {
+                component: "minor",
+                value: minor,
+            })?,
             patch: 0,
             pre: Prerelease::EMPTY,
             build: BuildMetadata::EMPTY,
-        }
+        })
     }
 
-    fn version_with_bumped_patch(major: u64, minor: u64, patch: u64) -> semver::Version {
-        semver::Version {
+    fn version_with_bumped_patch(
+        major: u64,
+        minor: u64,
+        patch: u64,
+    ) -> Result<semver::Version, Error> {
+        Ok(semver::Version {
             major,
             minor,
-            patch: patch
-                .checked_add(1)
-                .unwrap_or_else(|| panic!("Unexpectedly high patch version: `{patch}`")),
+            patch: patch.checked_add(1).ok_or(Error::Overflow {
+                component: "patch",
+                value: patch,
+            })?,
             pre: Prerelease::EMPTY,
             build: BuildMetadata::EMPTY,
+        })
+    }
+
+    fn version_with_pre(major: u64, minor: u64, patch: u64, pre: &Prerelease) -> semver::Version {
+        semver::Version {
+            major,
+            minor,
+            patch,
+            pre: pre.clone(),
+            build: BuildMetadata::EMPTY,
         }
     }
 
-    fn caret_range(major: u64, minor: Option<u64>, patch: Option<u64>) -> Range<semver::Version> {
+    /// Lowest version with higher precedence than `I.J.K-pre`.  Appending a `0` identifier gives
+    /// the immediate successor of a pre-release version, while `I.J.(K+1)` follows a release.
+    fn version_successor(
+        major: u64,
+        minor: u64,
+        patch: u64,
+        pre: &Prerelease,
+    ) -> Result<semver::Version, Error> {
+        if pre.is_empty() {
+            return Self::version_with_bumped_patch(major, minor, patch);
+        }
+
+        Ok(semver::Version {
+            major,
+            minor,
+            patch,
+            pre: Prerelease::new(&format!("{pre}.0"))
+                .expect("Extending a valid pre-release with `.0` should be valid"),
+            build: BuildMetadata::EMPTY,
+        })
+    }
+
+    fn caret_range(
+        major: u64,
+        minor: Option<u64>,
+        patch: Option<u64>,
+        pre: &Prerelease,
+    ) -> Result<Range<semver::Version>, Error> {
         match major {
             0 => {
                 match minor {
@@ -144,25 +439,26 @@ impl Version {
                         match patch {
                             Some(patch_version) => {
                                 // ^0.0.K
-                                Range {
-                                    start: semver::Version::new(
+                                Ok(Range {
+                                    start: Self::version_with_pre(
                                         major,
                                         minor_version,
                                         patch_version,
+                                        pre,
                                     ),
                                     end: Self::version_with_bumped_patch(
                                         major,
                                         minor_version,
                                         patch_version,
-                                    ),
-                                }
+                                    )?,
+                                })
                             }
                             None => {
                                 // ^0.0
-                                Range {
+                                Ok(Range {
                                     start: semver::Version::new(major, minor_version, 0),
-                                    end: Self::version_with_bumped_minor(major, minor_version),
-                                }
+                                    end: Self::version_with_bumped_minor(major, minor_version)?,
+                                })
                             }
                         }
                     }
@@ -170,103 +466,114 @@ impl Version {
                         match patch {
                             Some(patch_version) => {
                                 // ^0.J.K (J > 0)
-                                Range {
-                                    start: semver::Version::new(
+                                Ok(Range {
+                                    start: Self::version_with_pre(
                                         major,
                                         minor_version,
                                         patch_version,
+                                        pre,
                                     ),
-                                    end: Self::version_with_bumped_minor(major, minor_version),
-                                }
+                                    end: Self::version_with_bumped_minor(major, minor_version)?,
+                                })
                             }
                             None => {
                                 // ^0.J (J > 0)
-                                Range {
+                                Ok(Range {
                                     start: semver::Version::new(major, minor_version, 0),
-                                    end: Self::version_with_bumped_minor(major, minor_version),
-                                }
+                                    end: Self::version_with_bumped_minor(major, minor_version)?,
+                                })
                             }
                         }
                     }
                     // ^0
-                    None => Range {
+                    None => Ok(Range {
                         start: semver::Version::new(0, 0, 0),
                         end: semver::Version::new(1, 0, 0),
-                    },
+                    }),
                 }
             }
             1.. => {
                 if let Some(minor_version) = minor {
                     if let Some(patch_version) = patch {
                         // ^I.J.K (I > 0)
-                        Range {
-                            start: semver::Version::new(major, minor_version, patch_version),
-                            end: Self::version_with_bumped_major(major),
-                        }
+                        Ok(Range {
+                            start: Self::version_with_pre(major, minor_version, patch_version, pre),
+                            end: Self::version_with_bumped_major(major)?,
+                        })
                     } else {
                         // ^I.J (I > 0, J > 0)
-                        Range {
+                        Ok(Range {
                             start: semver::Version::new(major, minor_version, 0),
-                            end: Self::version_with_bumped_major(major),
-                        }
+                            end: Self::version_with_bumped_major(major)?,
+                        })
                     }
                 } else {
                     // ^I
-                    Range {
+                    Ok(Range {
                         start: semver::Version::new(major, 0, 0),
-                        end: Self::version_with_bumped_major(major),
-                    }
+                        end: Self::version_with_bumped_major(major)?,
+                    })
                 }
             }
         }
     }
 
-    fn exact_range(major: u64, minor: Option<u64>, patch: Option<u64>) -> Range<semver::Version> {
+    fn exact_range(
+        major: u64,
+        minor: Option<u64>,
+        patch: Option<u64>,
+        pre: &Prerelease,
+    ) -> Result<Range<semver::Version>, Error> {
         if let Some(minor_version) = minor {
             if let Some(patch_version) = patch {
                 // =I.J.K
-                Range {
-                    start: semver::Version::new(major, minor_version, patch_version),
-                    end: Self::version_with_bumped_patch(major, minor_version, patch_version),
-                }
+                Ok(Range {
+                    start: Self::version_with_pre(major, minor_version, patch_version, pre),
+                    end: Self::version_successor(major, minor_version, patch_version, pre)?,
+                })
             } else {
                 // =I.J
-                Range {
+                Ok(Range {
                     start: semver::Version::new(major, minor_version, 0),
-                    end: Self::version_with_bumped_minor(major, minor_version),
-                }
+                    end: Self::version_with_bumped_minor(major, minor_version)?,
+                })
             }
         } else {
             // =I
-            Range {
+            Ok(Range {
                 start: semver::Version::new(major, 0, 0),
-                end: Self::version_with_bumped_major(major),
-            }
+                end: Self::version_with_bumped_major(major)?,
+            })
         }
     }
 
-    fn greater_range(major: u64, minor: Option<u64>, patch: Option<u64>) -> Range<semver::Version> {
+    fn greater_range(
+        major: u64,
+        minor: Option<u64>,
+        patch: Option<u64>,
+        pre: &Prerelease,
+    ) -> Result<Range<semver::Version>, Error> {
         let end = semver::Version::new(u64::MAX, u64::MAX, u64::MAX);
         if let Some(minor_version) = minor {
             if let Some(patch_version) = patch {
                 // >I.J.K
-                Range {
-                    start: Self::version_with_bumped_patch(major, minor_version, patch_version),
+                Ok(Range {
+                    start: Self::version_successor(major, minor_version, patch_version, pre)?,
                     end,
-                }
+                })
             } else {
                 // >I.J
-                Range {
-                    start: Self::version_with_bumped_minor(major, minor_version),
+                Ok(Range {
+                    start: Self::version_with_bumped_minor(major, minor_version)?,
                     end,
-                }
+                })
             }
         } else {
             // >I
-            Range {
-                start: Self::version_with_bumped_major(major),
+            Ok(Range {
+                start: Self::version_with_bumped_major(major)?,
                 end,
-            }
+            })
         }
     }
 
@@ -274,13 +581,14 @@ impl Version {
         major: u64,
         minor: Option<u64>,
         patch: Option<u64>,
+        pre: &Prerelease,
     ) -> Range<semver::Version> {
         let end = semver::Version::new(u64::MAX, u64::MAX, u64::MAX);
         if let Some(minor_version) = minor {
             if let Some(patch_version) = patch {
                 // >=I.J.K
                 Range {
-                    start: semver::Version::new(major, minor_version, patch_version),
+                    start: Self::version_with_pre(major, minor_version, patch_version, pre),
                     end,
                 }
             } else {
@@ -299,14 +607,19 @@ impl Version {
         }
     }
 
-    fn less_range(major: u64, minor: Option<u64>, patch: Option<u64>) -> Range<semver::Version> {
+    fn less_range(
+        major: u64,
+        minor: Option<u64>,
+        patch: Option<u64>,
+        pre: &Prerelease,
+    ) -> Range<semver::Version> {
         let start = semver::Version::new(0, 0, 0);
         if let Some(minor_version) = minor {
             if let Some(patch_version) = patch {
                 // <I.J.K
                 Range {
                     start,
-                    end: semver::Version::new(major, minor_version, patch_version),
+                    end: Self::version_with_pre(major, minor_version, patch_version, pre),
                 }
             } else {
                 // <I.J
@@ -328,52 +641,58 @@ impl Version {
         major: u64,
         minor: Option<u64>,
         patch: Option<u64>,
-    ) -> Range<semver::Version> {
+        pre: &Prerelease,
+    ) -> Result<Range<semver::Version>, Error> {
         let start = semver::Version::new(0, 0, 0);
         if let Some(minor_version) = minor {
             if let Some(patch_version) = patch {
                 // <=I.J.K
-                Range {
+                Ok(Range {
                     start,
-                    end: Self::version_with_bumped_patch(major, minor_version, patch_version),
-                }
+                    end: Self::version_successor(major, minor_version, patch_version, pre)?,
+                })
             } else {
                 // <=I.J
-                Range {
+                Ok(Range {
                     start,
-                    end: Self::version_with_bumped_minor(major, minor_version),
-                }
+                    end: Self::version_with_bumped_minor(major, minor_version)?,
+                })
             }
         } else {
             // <=I
-            Range {
+            Ok(Range {
                 start,
-                end: Self::version_with_bumped_major(major),
-            }
+                end: Self::version_with_bumped_major(major)?,
+            })
         }
     }
 
-    fn tilde_range(major: u64, minor: Option<u64>, patch: Option<u64>) -> Range<semver::Version> {
+    fn tilde_range(
+        major: u64,
+        minor: Option<u64>,
+        patch: Option<u64>,
+        pre: &Prerelease,
+    ) -> Result<Range<semver::Version>, Error> {
         if let Some(minor_version) = minor {
             if let Some(patch_version) = patch {
                 // ~I.J.K — equivalent to `>=I.J.K, <I.(J+1).0`
-                Range {
-                    start: semver::Version::new(major, minor_version, patch_version),
-                    end: Self::version_with_bumped_minor(major, minor_version),
-                }
+                Ok(Range {
+                    start: Self::version_with_pre(major, minor_version, patch_version, pre),
+                    end: Self::version_with_bumped_minor(major, minor_version)?,
+                })
             } else {
                 // ~I.J — equivalent to `=I.J`
-                Range {
+                Ok(Range {
                     start: semver::Version::new(major, minor_version, 0),
-                    end: Self::version_with_bumped_minor(major, minor_version),
-                }
+                    end: Self::version_with_bumped_minor(major, minor_version)?,
+                })
             }
         } else {
             // ~I — equivalent to `=I`
-            Range {
+            Ok(Range {
                 start: semver::Version::new(major, 0, 0),
-                end: Self::version_with_bumped_major(major),
-            }
+                end: Self::version_with_bumped_major(major)?,
+            })
         }
     }
 
@@ -381,31 +700,27 @@ impl Version {
         major: u64,
         minor: Option<u64>,
         patch: Option<u64>,
-    ) -> Range<semver::Version> {
+    ) -> Result<Range<semver::Version>, Error> {
         debug_assert!(patch.is_none());
 
         if let Some(minor_version) = minor {
             // I.J.* — equivalent to `=I.J`
-            Range {
+            Ok(Range {
                 start: semver::Version::new(major, minor_version, 0),
-                end: Self::version_with_bumped_minor(major, minor_version),
-            }
+                end: Self::version_with_bumped_minor(major, minor_version)?,
+            })
         } else {
             // `I.*` or `I.*.*` — equivalent to `=I`
-            Range {
+            Ok(Range {
                 start: semver::Version::new(major, 0, 0),
-                end: Self::version_with_bumped_major(major),
-            }
+                end: Self::version_with_bumped_major(major)?,
+            })
         }
     }
 
-    /// Returns a vector containing the ranges for each comparator.  Collapses all ranges into a
-    /// single one if possible.  If collapsing to a single range is not possible, no attempt is
-    /// made to collapse any pairs of elements, which could feasibly be collapsed.  Result is
-    /// sorted by increasing range starts.
-    fn comparator_ranges(&self) -> Range<semver::Version> {
-        debug_assert!(!self.req.comparators.is_empty());
-
+    /// Returns the range of versions matching every comparator.  A requirement with no
+    /// comparators (`*`) matches every version.
+    fn comparator_ranges(&self) -> Result<Range<semver::Version>, Error> {
         let mut start = semver::Version::new(0, 0, 0);
         let mut end = semver::Version::new(u64::MAX, u64::MAX, u64::MAX);
         for comparator in &self.req.comparators {
@@ -414,98 +729,121 @@ impl Version {
                 major,
                 minor,
                 patch,
-                ..
+                pre,
             } = comparator;
             let range = match op {
-                Op::Exact => Self::exact_range(*major, *minor, *patch),
-                Op::Greater => Self::greater_range(*major, *minor, *patch),
-                Op::GreaterEq => Self::greater_or_equal_range(*major, *minor, *patch),
-                Op::Less => Self::less_range(*major, *minor, *patch),
-                Op::LessEq => Self::less_or_equal_range(*major, *minor, *patch),
-                Op::Tilde => Self::tilde_range(*major, *minor, *patch),
-                Op::Caret => Self::caret_range(*major, *minor, *patch),
+                Op::Exact => Self::exact_range(*major, *minor, *patch, pre),
+                Op::Greater => Self::greater_range(*major, *minor, *patch, pre),
+                Op::GreaterEq => Ok(Self::greater_or_equal_range(*major, *minor, *patch, pre)),
+                Op::Less => Ok(Self::less_range(*major, *minor, *patch, pre)),
+                Op::LessEq => Self::less_or_equal_range(*major, *minor, *patch, pre),
+                Op::Tilde => Self::tilde_range(*major, *minor, *patch, pre),
+                Op::Caret => Self::caret_range(*major, *minor, *patch, pre),
                 Op::Wildcard => Self::wildcard_range(*major, *minor, *patch),
-                _ => unimplemented!("Unsupported semver operator: `{op:?}`"),
-            };
+                _ => Err(Error::UnsupportedOperator(format!("{op:?}"))),
+            }?;
 
-            match op {
-                Op::Exact | Op::Tilde | Op::Caret | Op::Wildcard => {
-                    if range.start > start {
-                        start = range.start;
-                    }
-                    if range.end < end {
-                        end = range.end;
-                    }
-                }
-                Op::Greater | Op::GreaterEq => {
-                    if range.start > start {
-                        start = range.start;
-                    }
-                }
-                Op::Less | Op::LessEq => {
-                    if range.end < end {
-                        end = range.end;
-                    }
-                }
-                _ => unreachable!(
-                    "Previous match statement should cause a panic on non-implemented operations."
-                ),
+            // one-sided ranges are unbounded on the other side, so intersecting handles every
+            // operator
+            if range.start > start {
+                start = range.start;
+            }
+            if range.end < end {
+                end = range.end;
             }
         }
 
-        if end < start {
-            log::error!(
-                "Unexpected invalid range requirement: {:?}",
-                self.req.comparators,
-            );
-        }
-        Range { start, end }
+        Ok(Range { start, end })
     }
 
-    pub fn change_type(&self, other: &Self) -> Change {
-        let Comparator {
-            major,
-            minor,
-            patch,
-            ..
-        } = self.req.comparators.first().expect("Index should be valid");
-        let Comparator {
-            major: other_major,
-            minor: other_minor,
-            patch: other_patch,
-            ..
-        } = other
-            .req
+    /// Matches any version at all (`*`)
+    pub fn is_wildcard(&self) -> bool {
+        self.req.comparators.is_empty()
+    }
+
+    /// Has no upper bound, for example `>=1.2`, though `*` is left to [`Self::is_wildcard`]
+    pub fn is_unbounded(&self) -> Result<bool, Error> {
+        Ok(!self.is_wildcard()
+            && self.comparator_ranges()?.end == semver::Version::new(u64::MAX, u64::MAX, u64::MAX))
+    }
+
+    /// Includes an exact `=` comparator
+    pub fn is_exact_pin(&self) -> bool {
+        self.req
             .comparators
-            .first()
-            .expect("Index should be valid");
-        debug_assert!(minor.is_some() || patch.is_none());
-        debug_assert!(other_minor.is_some() || other_patch.is_none());
-        if major != other_major {
-            return Change::Major;
-        }
-        if let (Some(self_minor), Some(other_minor)) = (minor, other_minor) {
-            if self_minor != other_minor {
-                if *major > 0 {
-                    return Change::Minor;
-                }
-                return Change::Major;
+            .iter()
+            .any(|comparator| comparator.op == Op::Exact)
+    }
+
+    /// Includes a `~0` or `~0.0.x` comparator.  Unlike `^`, these allow breaking changes, for
+    /// example `~0.0.3` matches `0.0.4`.  `~0.J.K`, with `J` above zero, matches the same range as
+    /// `^0.J.K`, so is not included.
+    pub fn is_tilde_on_zero_major(&self) -> bool {
+        self.req.comparators.iter().any(|comparator| {
+            comparator.op == Op::Tilde
+                && comparator.major == 0
+                && comparator.minor.is_none_or(|minor| minor == 0)
+        })
+    }
+
+    /// Lowest version matching the requirement
+    pub fn lower_bound(&self) -> Result<semver::Version, Error> {
+        Ok(self.comparator_ranges()?.start)
+    }
+
+    /// Returns `false` when no version can match the requirement, for example `>=2, <1.5`, or
+    /// `>1.0.0-rc.1, <1.0.0-rc.1.0`, where the range is empty once pre-release precedence is
+    /// applied
+    pub fn is_satisfiable(&self) -> Result<bool, Error> {
+        Ok(!self.comparator_ranges()?.is_empty())
+    }
+
+    /// Size of the move from version `b` to version `a`, following Cargo semver compatibility
+    /// rules.  So, changing the left-most non-zero component is a major change, and, for
+    /// example, `0.1.2` to `0.1.3` is a minor change.
+    fn version_change(a: &semver::Version, b: &semver::Version) -> Change {
+        if a == b {
+            Change::None
+        } else if a.major != b.major {
+            Change::Major
+        } else if a.minor != b.minor {
+            if a.major > 0 {
+                Change::Minor
+            } else {
+                Change::Major
             }
-            if let (Some(self_patch), Some(other_patch)) = (patch, other_patch) {
-                if self_patch != other_patch {
-                    if *major > 0 {
-                        return Change::Patch;
-                    }
-                    if *self_minor > 0 {
-                        return Change::Minor;
-                    }
-                    return Change::Major;
-                }
-                return Change::None;
+        } else if a.patch != b.patch {
+            if a.major > 0 {
+                Change::Patch
+            } else if a.minor > 0 {
+                Change::Minor
+            } else {
+                Change::Major
             }
+        } else {
+            Change::PreRelease
         }
+    }
+
+    /// Size and direction of the moves in the lower and upper bounds of the range of versions
+    /// matching this requirement, relative to the `other` requirement
+    pub fn bounds_change(&self, other: &Self) -> Result<BoundsChange, Error> {
+        let range = self.comparator_ranges()?;
+        let other_range = other.comparator_ranges()?;
+
+        Ok(BoundsChange {
+            lower: Self::version_change(&range.start, &other_range.start),
+            upper: Self::version_change(&range.end, &other_range.end),
+            floor: BoundMove::new(&range.start, &other_range.start),
+            ceiling: BoundMove::new_upper(&range.end, &other_range.end),
+            empty: range.is_empty() || other_range.is_empty(),
+        })
+    }
 
-        Change::Unknown
+    /// Classifies the change from the `other` requirement using the whole range of matching
+    /// versions, rather than any single comparator
+    pub fn change_type(&self, other: &Self) -> Result<Change, Error> {
+        Ok(self.bounds_change(other)?.change_type())
     }
 
     fn fmt_comparator_version(
@@ -2,17 +2,18 @@ use std::{cmp::Ordering, ops::Range};
 
 use semver::{Comparator, Op, Prerelease, VersionReq};
 
-use super::Change;
+use super::{BoundMove, Change, Error, RangeRelationship};
 use crate::domain::{semver::Version, SemverVersion};
 
 #[test]
 fn fmt_semver_change_displays_expected_values() {
     // act
     let result = format!(
-        "Major: {}\nMinor: {}\nPatch: {}\nNone: {}\nUnknown: {}",
+        "Major: {}\nMinor: {}\nPatch: {}\nPreRelease: {}\nNone: {}\nUnknown: {}",
         Change::Major,
         Change::Minor,
         Change::Patch,
+        Change::PreRelease,
         Change::None,
         Change::Unknown
     );
@@ -774,65 +775,127 @@ fn change_type_returns_expected_values() {
     assert_eq!(
         SemverVersion::new("1.2.3")
             .unwrap()
-            .change_type(&SemverVersion::new("1.2.3").unwrap()),
+            .change_type(&SemverVersion::new("1.2.3").unwrap())
+            .unwrap(),
         Change::None
     );
     assert_eq!(
         SemverVersion::new("1.2.3")
             .unwrap()
-            .change_type(&SemverVersion::new("2.2.3").unwrap()),
+            .change_type(&SemverVersion::new("2.2.3").unwrap())
+            .unwrap(),
         Change::Major
     );
     assert_eq!(
         SemverVersion::new("0.2.3")
             .unwrap()
-            .change_type(&SemverVersion::new("0.3.3").unwrap()),
+            .change_type(&SemverVersion::new("0.3.3").unwrap())
+            .unwrap(),
         Change::Major
     );
     assert_eq!(
         SemverVersion::new("0.0.3")
             .unwrap()
-            .change_type(&SemverVersion::new("0.0.4").unwrap()),
+            .change_type(&SemverVersion::new("0.0.4").unwrap())
+            .unwrap(),
         Change::Major
     );
     assert_eq!(
         SemverVersion::new("1.2.3")
             .unwrap()
-            .change_type(&SemverVersion::new("1.3.3").unwrap()),
+            .change_type(&SemverVersion::new("1.3.3").unwrap())
+            .unwrap(),
         Change::Minor
     );
     assert_eq!(
         SemverVersion::new("0.1.2")
             .unwrap()
-            .change_type(&SemverVersion::new("0.1.3").unwrap()),
+            .change_type(&SemverVersion::new("0.1.3").unwrap())
+            .unwrap(),
         Change::Minor
     );
     assert_eq!(
         SemverVersion::new("1.2.3")
             .unwrap()
-            .change_type(&SemverVersion::new("1.2.4").unwrap()),
+            .change_type(&SemverVersion::new("1.2.4").unwrap())
+            .unwrap(),
         Change::Patch
     );
     assert_eq!(
         SemverVersion::new("1.2.3")
             .unwrap()
-            .change_type(&SemverVersion::new("1").unwrap()),
-        Change::Unknown
+            .change_type(&SemverVersion::new("1").unwrap())
+            .unwrap(),
+        Change::Minor
     );
     assert_eq!(
         SemverVersion::new("1.2.3")
             .unwrap()
-            .change_type(&SemverVersion::new("1.2").unwrap()),
-        Change::Unknown
+            .change_type(&SemverVersion::new("1.2").unwrap())
+            .unwrap(),
+        Change::Patch
     );
     assert_eq!(
         SemverVersion::new("1.2")
             .unwrap()
-            .change_type(&SemverVersion::new("1").unwrap()),
+            .change_type(&SemverVersion::new("1").unwrap())
+            .unwrap(),
+        Change::Minor
+    );
+    assert_eq!(
+        SemverVersion::new(">=1.2, <2.0")
+            .unwrap()
+            .change_type(&SemverVersion::new(">=1.2, <1.5").unwrap())
+            .unwrap(),
+        Change::Major
+    );
+    assert_eq!(
+        SemverVersion::new(">=2, <1.5")
+            .unwrap()
+            .change_type(&SemverVersion::new("1.2").unwrap())
+            .unwrap(),
         Change::Unknown
     );
 }
 
+#[test]
+fn bounds_change_reports_each_bound_separately() {
+    // arrange
+    let version = SemverVersion::new(">=1.2, <2.0").unwrap();
+    let other = SemverVersion::new(">=1.2, <1.5").unwrap();
+
+    // act
+    let outcome = version.bounds_change(&other).unwrap();
+
+    // assert
+    assert_eq!(outcome.lower, Change::None);
+    assert_eq!(outcome.upper, Change::Major);
+    assert_eq!(format!("{outcome}"), String::from("ceiling raised major"));
+
+    // arrange
+    let version = SemverVersion::new(">=1.3, <1.6").unwrap();
+    let other = SemverVersion::new(">=1.2, <1.5").unwrap();
+
+    // act
+    let outcome = version.bounds_change(&other).unwrap();
+
+    // assert
+    assert_eq!(
+        format!("{outcome}"),
+        String::from("floor raised minor, ceiling raised minor")
+    );
+
+    // arrange
+    let version = SemverVersion::new("1.2.4").unwrap();
+    let other = SemverVersion::new("1.2.3").unwrap();
+
+    // act
+    let outcome = version.bounds_change(&other).unwrap();
+
+    // assert
+    assert_eq!(format!("{outcome}"), String::from("floor raised patch"));
+}
+
 #[test]
 fn fmt_returns_expected_value_for_prerelease_requirement() {
     // arrange
@@ -861,7 +924,7 @@ fn comparator_ranges_returns_expected_value() {
     let version = Version::new(">=1.2.3, <3").unwrap();
 
     // act
-    let Range { start, end } = version.comparator_ranges();
+    let Range { start, end } = version.comparator_ranges().unwrap();
 
     // assert
 
@@ -872,10 +935,358 @@ fn comparator_ranges_returns_expected_value() {
     let version = Version::new(">=1.2.3, <3, >=1.4.6, <1.4.7").unwrap();
 
     // act
-    let Range { start, end } = version.comparator_ranges();
+    let Range { start, end } = version.comparator_ranges().unwrap();
 
     // assert
 
     assert_eq!(start, semver::Version::new(1, 4, 6));
     assert_eq!(end, semver::Version::new(1, 4, 7));
 }
+
+#[test]
+fn semver_version_applies_partial_order_as_expected_for_prerelease_requirements() {
+    // assert
+    assert!(
+        SemverVersion::new("1.0.0-alpha.1").unwrap() < SemverVersion::new("1.0.0-beta.2").unwrap()
+    );
+    assert!(SemverVersion::new("1.0.0-alpha.1").unwrap() < SemverVersion::new("1.0.0").unwrap());
+    assert!(
+        SemverVersion::new("1.0.0-alpha.2").unwrap() > SemverVersion::new("1.0.0-alpha.1").unwrap()
+    );
+    assert!(
+        SemverVersion::new("=1.0.0-rc.1").unwrap() < SemverVersion::new("=1.0.0-rc.2").unwrap()
+    );
+    assert!(
+        SemverVersion::new(">1.0.0-rc.1").unwrap() > SemverVersion::new(">=1.0.0-rc.1").unwrap()
+    );
+    assert_eq!(
+        SemverVersion::new("<=2.0.0-rc.1")
+            .unwrap()
+            .partial_cmp(&SemverVersion::new("<2.0.0-rc.1").unwrap()),
+        Some(Ordering::Greater)
+    );
+}
+
+#[test]
+fn change_type_returns_prerelease_for_prerelease_only_changes() {
+    // assert
+    assert_eq!(
+        SemverVersion::new("1.0.0-beta.2")
+            .unwrap()
+            .change_type(&SemverVersion::new("1.0.0-alpha.1").unwrap())
+            .unwrap(),
+        Change::PreRelease
+    );
+    assert_eq!(
+        SemverVersion::new("1.0.0")
+            .unwrap()
+            .change_type(&SemverVersion::new("1.0.0-rc.1").unwrap())
+            .unwrap(),
+        Change::PreRelease
+    );
+    assert_eq!(
+        SemverVersion::new("1.0.1-alpha.1")
+            .unwrap()
+            .change_type(&SemverVersion::new("1.0.0-alpha.1").unwrap())
+            .unwrap(),
+        Change::Patch
+    );
+}
+
+#[test]
+fn comparator_ranges_keeps_prerelease_identifiers() {
+    // arrange
+    let version = Version::new("=1.0.0-alpha.1").unwrap();
+
+    // act
+    let Range { start, end } = version.comparator_ranges().unwrap();
+
+    // assert
+    assert_eq!(start, semver::Version::parse("1.0.0-alpha.1").unwrap());
+    assert_eq!(end, semver::Version::parse("1.0.0-alpha.1.0").unwrap());
+
+    // arrange
+    let version = Version::new("^1.0.0-beta.2").unwrap();
+
+    // act
+    let Range { start, end } = version.comparator_ranges().unwrap();
+
+    // assert
+    assert_eq!(start, semver::Version::parse("1.0.0-beta.2").unwrap());
+    assert_eq!(end, semver::Version::new(2, 0, 0));
+}
+
+#[test]
+fn relationship_describes_range_changes() {
+    // assert
+    assert_eq!(
+        SemverVersion::new("1.0")
+            .unwrap()
+            .relationship(&SemverVersion::new("1").unwrap())
+            .unwrap(),
+        RangeRelationship::NotationOnly
+    );
+    assert_eq!(
+        SemverVersion::new("1.2.3")
+            .unwrap()
+            .relationship(&SemverVersion::new("1.2.3").unwrap())
+            .unwrap(),
+        RangeRelationship::Unchanged
+    );
+    assert_eq!(
+        SemverVersion::new(">=1.2, <2.0")
+            .unwrap()
+            .relationship(&SemverVersion::new(">=1.2, <1.5").unwrap())
+            .unwrap(),
+        RangeRelationship::Widened {
+            lower: false,
+            upper: true
+        }
+    );
+    assert_eq!(
+        SemverVersion::new(">=1.3, <1.4")
+            .unwrap()
+            .relationship(&SemverVersion::new(">=1.2, <1.5").unwrap())
+            .unwrap(),
+        RangeRelationship::Narrowed {
+            lower: true,
+            upper: true
+        }
+    );
+    assert_eq!(
+        SemverVersion::new(">=2.9, <5.9.2")
+            .unwrap()
+            .relationship(&SemverVersion::new(">=1.2.3, <3").unwrap())
+            .unwrap(),
+        RangeRelationship::ShiftedUp
+    );
+    assert_eq!(
+        SemverVersion::new(">=1.2.3, <3")
+            .unwrap()
+            .relationship(&SemverVersion::new(">=2.9, <5.9.2").unwrap())
+            .unwrap(),
+        RangeRelationship::ShiftedDown
+    );
+    assert_eq!(
+        SemverVersion::new("2")
+            .unwrap()
+            .relationship(&SemverVersion::new("1").unwrap())
+            .unwrap(),
+        RangeRelationship::DisjointAbove
+    );
+    assert_eq!(
+        SemverVersion::new("0.14")
+            .unwrap()
+            .relationship(&SemverVersion::new("0.15.8").unwrap())
+            .unwrap(),
+        RangeRelationship::DisjointBelow
+    );
+    assert_eq!(
+        SemverVersion::new(">=2, <1.5")
+            .unwrap()
+            .relationship(&SemverVersion::new("1.2").unwrap())
+            .unwrap(),
+        RangeRelationship::Empty
+    );
+}
+
+#[test]
+fn fmt_range_relationship_displays_expected_values() {
+    // assert
+    assert_eq!(
+        format!("{}", RangeRelationship::NotationOnly),
+        String::from("notation only")
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            RangeRelationship::Widened {
+                lower: true,
+                upper: true
+            }
+        ),
+        String::from("widened")
+    );
+    assert_eq!(
+        format!("{}", RangeRelationship::ShiftedDown),
+        String::from("shifted down")
+    );
+    assert_eq!(
+        format!("{}", RangeRelationship::DisjointAbove),
+        String::from("disjoint, above previous range")
+    );
+}
+
+#[test]
+fn comparator_ranges_returns_error_on_overflow() {
+    // arrange
+    let version = Version::new("18446744073709551615").unwrap();
+
+    // act
+    let outcome = version.comparator_ranges();
+
+    // assert
+    assert_eq!(
+        outcome,
+        Err(Error::Overflow {
+            component: "major",
+            value: u64::MAX
+        })
+    );
+
+    // arrange
+    let version = Version::new("~1.18446744073709551615").unwrap();
+
+    // act
+    let outcome = version.comparator_ranges();
+
+    // assert
+    assert_eq!(
+        outcome,
+        Err(Error::Overflow {
+            component: "minor",
+            value: u64::MAX
+        })
+    );
+}
+
+#[test]
+fn comparator_ranges_returns_full_range_for_wildcard_requirement() {
+    // arrange
+    let version = Version::new("*").unwrap();
+
+    // act
+    let Range { start, end } = version.comparator_ranges().unwrap();
+
+    // assert
+    assert_eq!(start, semver::Version::new(0, 0, 0));
+    assert_eq!(end, semver::Version::new(u64::MAX, u64::MAX, u64::MAX));
+}
+
+#[test]
+fn semver_version_is_not_comparable_when_range_overflows() {
+    // arrange
+    let version = SemverVersion::new("18446744073709551615").unwrap();
+    let other = SemverVersion::new("1").unwrap();
+
+    // act
+    let outcome = version.partial_cmp(&other);
+
+    // assert
+    assert_eq!(outcome, None);
+    assert_eq!(
+        format!("{}", version.change_type(&other).unwrap_err()),
+        String::from("Unexpectedly high major version: `18446744073709551615`")
+    );
+}
+
+#[test]
+fn fmt_error_displays_expected_values() {
+    // assert
+    assert_eq!(
+        format!("{}", Error::UnsupportedOperator(String::from("Unknown"))),
+        String::from("Unsupported semver operator: `Unknown`")
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Error::Overflow {
+                component: "patch",
+                value: u64::MAX
+            }
+        ),
+        String::from("Unexpectedly high patch version: `18446744073709551615`")
+    );
+}
+
+#[test]
+fn is_satisfiable_returns_false_for_empty_ranges() {
+    // assert
+    assert!(SemverVersion::new("1.2").unwrap().is_satisfiable().unwrap());
+    assert!(SemverVersion::new("=1.0.0-alpha.1")
+        .unwrap()
+        .is_satisfiable()
+        .unwrap());
+    assert!(!SemverVersion::new(">=2, <1.5")
+        .unwrap()
+        .is_satisfiable()
+        .unwrap());
+    assert!(!SemverVersion::new(">1.0.0, <1.0.1")
+        .unwrap()
+        .is_satisfiable()
+        .unwrap());
+    assert!(!SemverVersion::new(">=1.0.0-rc.1, <1.0.0-rc.1")
+        .unwrap()
+        .is_satisfiable()
+        .unwrap());
+}
+
+#[test]
+fn lint_predicates_return_expected_values() {
+    // assert
+    assert!(SemverVersion::new("*").unwrap().is_wildcard());
+    assert!(!SemverVersion::new("1.*").unwrap().is_wildcard());
+    assert!(SemverVersion::new(">=1.4").unwrap().is_unbounded().unwrap());
+    assert!(SemverVersion::new(">1.4").unwrap().is_unbounded().unwrap());
+    assert!(!SemverVersion::new(">=1.4, <2")
+        .unwrap()
+        .is_unbounded()
+        .unwrap());
+    assert!(!SemverVersion::new("*").unwrap().is_unbounded().unwrap());
+    assert!(SemverVersion::new("=1.0.200").unwrap().is_exact_pin());
+    assert!(!SemverVersion::new("1.0.200").unwrap().is_exact_pin());
+    assert!(SemverVersion::new("~0.0.3")
+        .unwrap()
+        .is_tilde_on_zero_major());
+    assert!(SemverVersion::new("~0").unwrap().is_tilde_on_zero_major());
+    assert!(!SemverVersion::new("~0.15.8")
+        .unwrap()
+        .is_tilde_on_zero_major());
+    assert!(!SemverVersion::new("~1.15.8")
+        .unwrap()
+        .is_tilde_on_zero_major());
+    assert_eq!(
+        SemverVersion::new("^1.2").unwrap().lower_bound().unwrap(),
+        semver::Version::new(1, 2, 0)
+    );
+}
+
+#[test]
+fn bounds_change_reports_floor_and_ceiling_directions() {
+    // arrange
+    let version = SemverVersion::new(">=1.2").unwrap();
+    let other = SemverVersion::new("1.4").unwrap();
+
+    // act
+    let outcome = version.bounds_change(&other).unwrap();
+
+    // assert
+    assert_eq!(outcome.floor, BoundMove::Lowered);
+    assert_eq!(outcome.ceiling, BoundMove::Removed);
+    assert_eq!(
+        format!("{outcome}"),
+        String::from("floor lowered minor, ceiling removed")
+    );
+
+    // act
+    let outcome = other.bounds_change(&version).unwrap();
+
+    // assert
+    assert_eq!(outcome.floor, BoundMove::Raised);
+    assert_eq!(outcome.ceiling, BoundMove::Added);
+    assert_eq!(
+        format!("{outcome}"),
+        String::from("floor raised minor, ceiling added")
+    );
+
+    // arrange
+    let version = SemverVersion::new(">=1.2, <1.4").unwrap();
+    let other = SemverVersion::new(">=1.2, <1.5").unwrap();
+
+    // act
+    let outcome = version.bounds_change(&other).unwrap();
+
+    // assert
+    assert_eq!(outcome.floor, BoundMove::Unchanged);
+    assert_eq!(format!("{outcome}"), String::from("ceiling lowered minor"));
+}
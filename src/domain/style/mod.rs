@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests;
+
+use super::semver::Change;
+
+/// How text reports mark each line, chosen with the `--plain` and `--emoji` flags.
+/// Machine-readable formats always use their own fixed notation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Style {
+    #[default]
+    Emoji,
+
+    /// ASCII words, for terminals and CI logs which garble emoji, for example `MAJOR bump serde`
+    Plain,
+}
+
+/// Marker at the start of a text report line, or a label within it.  Where the emoji is followed
+/// by the verb it stands for, the plain word replaces both, so `✨ add` becomes `ADD`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mark {
+    Add,
+    Remove,
+    Rename,
+
+    /// Build target change
+    Target,
+
+    /// Duplicate introduced by a Cargo.toml change
+    DuplicateAlert,
+
+    /// Cargo.lock package source or checksum change
+    SupplyChain,
+
+    Duplicate,
+    Resolve,
+
+    /// Locked version of a Cargo.toml dependency
+    Locked,
+
+    /// Lock file already satisfies a changed requirement
+    LockSatisfied,
+
+    /// Lock file no longer satisfies a changed requirement
+    LockStale,
+
+    Warning,
+    Unsatisfiable,
+    Lint,
+    Changelog,
+
+    /// Nothing to report.  Includes the space before the message, since plain reports leave the
+    /// marker out.
+    Clean,
+
+    /// Label for Cargo.lock packages
+    CargoLock,
+
+    /// Separates previous and current values
+    Arrow,
+}
+
+impl Style {
+    pub fn mark(self, mark: Mark) -> &'static str {
+        match self {
+            Style::Emoji => match mark {
+                Mark::Add => "✨ add",
+                Mark::Remove => "🗑\u{fe0f} remove",
+                Mark::Rename => "🏷\u{fe0f} rename",
+                Mark::Target => "🎯",
+                Mark::DuplicateAlert => "🚨",
+                Mark::SupplyChain => "🛑",
+                Mark::Duplicate => "👯",
+                Mark::Resolve => "🧹 resolve",
+                Mark::Locked => "🔒",
+                Mark::LockSatisfied => "🔐",
+                Mark::LockStale => "🔓",
+                Mark::Warning => "⚠\u{fe0f}",
+                Mark::Unsatisfiable => "❌ unsatisfiable",
+                Mark::Lint => "🔍 lint",
+                Mark::Changelog => "📝",
+                Mark::Clean => "🧹 ",
+                Mark::CargoLock => "🔒 Cargo.lock",
+                Mark::Arrow => "→",
+            },
+            Style::Plain => match mark {
+                Mark::Add => "ADD",
+                Mark::Remove => "REMOVE",
+                Mark::Rename => "RENAME",
+                Mark::Target => "TARGET",
+                Mark::DuplicateAlert | Mark::SupplyChain => "ALERT",
+                Mark::Duplicate => "DUPLICATE",
+                Mark::Resolve => "RESOLVE",
+                Mark::Locked => "LOCKED",
+                Mark::LockSatisfied => "OK",
+                Mark::LockStale => "STALE",
+                Mark::Warning => "WARNING",
+                Mark::Unsatisfiable => "UNSATISFIABLE",
+                Mark::Lint => "LINT",
+                Mark::Changelog => "CHANGELOG",
+                Mark::Clean => "",
+                Mark::CargoLock => "Cargo.lock",
+                Mark::Arrow => "->",
+            },
+        }
+    }
+
+    /// Severity of a version move, for example `❗` in emoji, and `MAJOR` in plain
+    pub fn severity(self, change: &Change) -> String {
+        match self {
+            Style::Emoji => change.to_string(),
+            Style::Plain => change.description().to_uppercase(),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use super::{Mark, Style};
+use crate::domain::semver::Change;
+
+const MARKS: [Mark; 18] = [
+    Mark::Add,
+    Mark::Remove,
+    Mark::Rename,
+    Mark::Target,
+    Mark::DuplicateAlert,
+    Mark::SupplyChain,
+    Mark::Duplicate,
+    Mark::Resolve,
+    Mark::Locked,
+    Mark::LockSatisfied,
+    Mark::LockStale,
+    Mark::Warning,
+    Mark::Unsatisfiable,
+    Mark::Lint,
+    Mark::Changelog,
+    Mark::Clean,
+    Mark::CargoLock,
+    Mark::Arrow,
+];
+
+#[test]
+fn plain_style_uses_only_ascii() {
+    // act
+    let marks: String = MARKS
+        .into_iter()
+        .map(|mark| Style::Plain.mark(mark))
+        .collect();
+    let severities: String = [
+        Change::Major,
+        Change::Minor,
+        Change::Patch,
+        Change::PreRelease,
+        Change::None,
+        Change::Unknown,
+    ]
+    .iter()
+    .map(|change| Style::Plain.severity(change))
+    .collect();
+
+    // assert
+    assert!(marks.is_ascii());
+    assert!(severities.is_ascii());
+}
+
+#[test]
+fn severity_uses_emoji_or_upper_case_word() {
+    // act
+    let emoji = Style::Emoji.severity(&Change::Major);
+    let plain = Style::Plain.severity(&Change::PreRelease);
+
+    // assert
+    assert_eq!(emoji, String::from("❗"));
+    assert_eq!(plain, String::from("PRE-RELEASE"));
+}
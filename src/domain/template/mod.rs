@@ -0,0 +1,300 @@
+#[cfg(test)]
+mod tests;
+
+use std::{fs, path::Path, sync::LazyLock};
+
+use anyhow::{anyhow, Context};
+use serde::Deserialize;
+
+use super::{
+    change_set::{DependencyChange, Kind},
+    Style,
+};
+
+/// Built-in template, reproducing the emoji text report
+const DEFAULT_TEMPLATE: &str = include_str!("default.toml");
+
+/// Built-in template for the plain style, with words in place of emoji
+const PLAIN_TEMPLATE: &str = include_str!("plain.toml");
+
+static BUILTIN: LazyLock<Template> = LazyLock::new(|| {
+    Template::new_from_str("", Style::Emoji)
+        .unwrap_or_else(|error| unreachable!("Built-in template should be valid: {error:#}"))
+});
+
+static BUILTIN_PLAIN: LazyLock<Template> = LazyLock::new(|| {
+    Template::new_from_str("", Style::Plain)
+        .unwrap_or_else(|error| unreachable!("Built-in template should be valid: {error:#}"))
+});
+
+/// Values available to `[change]` templates, written as `{{name}}`
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Variable {
+    Name,
+    Package,
+    Section,
+    Label,
+    Old,
+    New,
+    OldRequirement,
+    NewRequirement,
+    Severity,
+    Emoji,
+    Tag,
+    Direction,
+    Detail,
+    Reason,
+    OldSource,
+    NewSource,
+    Line,
+}
+
+impl Variable {
+    const ALL: [Variable; 17] = [
+        Variable::Name,
+        Variable::Package,
+        Variable::Section,
+        Variable::Label,
+        Variable::Old,
+        Variable::New,
+        Variable::OldRequirement,
+        Variable::NewRequirement,
+        Variable::Severity,
+        Variable::Emoji,
+        Variable::Tag,
+        Variable::Direction,
+        Variable::Detail,
+        Variable::Reason,
+        Variable::OldSource,
+        Variable::NewSource,
+        Variable::Line,
+    ];
+
+    fn id(self) -> &'static str {
+        match self {
+            Variable::Name => "name",
+            Variable::Package => "package",
+            Variable::Section => "section",
+            Variable::Label => "label",
+            Variable::Old => "old",
+            Variable::New => "new",
+            Variable::OldRequirement => "old_requirement",
+            Variable::NewRequirement => "new_requirement",
+            Variable::Severity => "severity",
+            Variable::Emoji => "emoji",
+            Variable::Tag => "tag",
+            Variable::Direction => "direction",
+            Variable::Detail => "detail",
+            Variable::Reason => "reason",
+            Variable::OldSource => "old_source",
+            Variable::NewSource => "new_source",
+            Variable::Line => "line",
+        }
+    }
+
+    fn value(self, change: &DependencyChange, style: Style) -> String {
+        match self {
+            Variable::Name => change.name.clone(),
+            Variable::Package => change.package.clone(),
+            Variable::Section => change.section.to_string(),
+            Variable::Label => match change.section.label(style) {
+                Some(label) => format!("{} {label}", change.package),
+                None => change.package.clone(),
+            },
+            Variable::Old => {
+                DependencyChange::fmt_requirement(change.previous_requirement.as_ref())
+            }
+            Variable::New => DependencyChange::fmt_requirement(change.current_requirement.as_ref()),
+            Variable::OldRequirement => change.previous_requirement.clone().unwrap_or_default(),
+            Variable::NewRequirement => change.current_requirement.clone().unwrap_or_default(),
+            Variable::Severity => String::from(change.change.description()),
+            Variable::Emoji => Style::Emoji.severity(&change.change),
+            Variable::Tag => Style::Plain.severity(&change.change),
+            Variable::Direction => String::from(change.kind.direction()),
+            Variable::Detail => change.fmt_detail(),
+            Variable::Reason => match &change.kind {
+                Kind::Skipped(reason) => reason.clone(),
+                _ => String::new(),
+            },
+            Variable::OldSource => match &change.kind {
+                Kind::SourceChanged { from, .. } => from.to_string(),
+                _ => String::new(),
+            },
+            Variable::NewSource => match &change.kind {
+                Kind::SourceChanged { to, .. } => to.to_string(),
+                _ => String::new(),
+            },
+            Variable::Line => change
+                .current_span
+                .or(change.previous_span)
+                .map(|span| span.line.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Text(String),
+    Variable(Variable),
+}
+
+/// Single line template, parsed so that rendering cannot fail
+#[derive(Debug, PartialEq)]
+struct Pattern(Vec<Segment>);
+
+impl Pattern {
+    fn new(pattern: &str) -> anyhow::Result<Self> {
+        let mut segments = Vec::new();
+        let mut rest = pattern;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Text(String::from(&rest[..start])));
+            }
+            let Some(length) = rest[start..].find("}}") else {
+                return Err(anyhow!("Unclosed `{{{{` in `{pattern}`"));
+            };
+            let id = rest[start + 2..start + length].trim();
+            let variable = Variable::ALL
+                .into_iter()
+                .find(|variable| variable.id() == id)
+                .ok_or_else(|| {
+                    let ids: Vec<&str> = Variable::ALL.iter().map(|value| value.id()).collect();
+                    anyhow!(
+                        "Unknown variable `{id}` in `{pattern}`.  Expected one of: {}",
+                        ids.join(", ")
+                    )
+                })?;
+            segments.push(Segment::Variable(variable));
+            rest = &rest[start + length + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(String::from(rest)));
+        }
+
+        Ok(Self(segments))
+    }
+
+    fn render(&self, change: &DependencyChange, style: Style) -> String {
+        self.0
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.clone(),
+                Segment::Variable(variable) => variable.value(change, style),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChangeTemplateFile {
+    add: Option<String>,
+    remove: Option<String>,
+    bump: Option<String>,
+    drop: Option<String>,
+    change: Option<String>,
+    notation: Option<String>,
+    source: Option<String>,
+    skip: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TemplateFile {
+    header: Option<String>,
+    footer: Option<String>,
+    no_changes: Option<String>,
+    change: Option<ChangeTemplateFile>,
+}
+
+/// Text report layout, read from a TOML file.  See `default.toml` for the built-in template and
+/// the variables available.
+#[derive(Debug)]
+pub struct Template {
+    /// Style of the built-in template, which also applies to section labels and to report lines
+    /// outside the template
+    pub style: Style,
+
+    pub header: String,
+    pub footer: String,
+
+    /// Replaces the change lines when neither Cargo.toml nor Cargo.lock has any
+    pub no_changes: String,
+
+    add: Pattern,
+    remove: Pattern,
+    bump: Pattern,
+    drop: Pattern,
+    change: Pattern,
+    notation: Pattern,
+    source: Pattern,
+    skip: Pattern,
+}
+
+impl Template {
+    pub fn new(path: &Path, style: Style) -> anyhow::Result<Self> {
+        let template_str = fs::read_to_string(path)
+            .with_context(|| format!("Error opening template file: `{}`", path.display()))?;
+
+        Self::new_from_str(&template_str, style)
+            .with_context(|| format!("Error parsing template `{}`", path.display()))
+    }
+
+    /// Keys missing from `template_str` take their value from the built-in template for `style`
+    pub fn new_from_str(template_str: &str, style: Style) -> anyhow::Result<Self> {
+        let file: TemplateFile =
+            toml::from_str(template_str).context("Creating `Template` from str")?;
+        let builtin = match style {
+            Style::Emoji => DEFAULT_TEMPLATE,
+            Style::Plain => PLAIN_TEMPLATE,
+        };
+        let defaults: TemplateFile =
+            toml::from_str(builtin).context("Parsing built-in template")?;
+        let change = file.change.unwrap_or_default();
+        let default_change = defaults.change.unwrap_or_default();
+        let pattern = |key: &str, value: Option<String>, default: Option<String>| {
+            Pattern::new(&value.or(default).unwrap_or_default())
+                .with_context(|| format!("Error in `change.{key}` template"))
+        };
+
+        Ok(Self {
+            style,
+            header: file.header.or(defaults.header).unwrap_or_default(),
+            footer: file.footer.or(defaults.footer).unwrap_or_default(),
+            no_changes: file.no_changes.or(defaults.no_changes).unwrap_or_default(),
+            add: pattern("add", change.add, default_change.add)?,
+            remove: pattern("remove", change.remove, default_change.remove)?,
+            bump: pattern("bump", change.bump, default_change.bump)?,
+            drop: pattern("drop", change.drop, default_change.drop)?,
+            change: pattern("change", change.change, default_change.change)?,
+            notation: pattern("notation", change.notation, default_change.notation)?,
+            source: pattern("source", change.source, default_change.source)?,
+            skip: pattern("skip", change.skip, default_change.skip)?,
+        })
+    }
+
+    /// Built-in template, which produces the text report in `style`
+    pub fn builtin(style: Style) -> &'static Self {
+        match style {
+            Style::Emoji => &BUILTIN,
+            Style::Plain => &BUILTIN_PLAIN,
+        }
+    }
+
+    /// Report line for the change, without a trailing newline
+    pub fn render_change(&self, change: &DependencyChange) -> String {
+        let pattern = match change.kind {
+            Kind::Added => &self.add,
+            Kind::Removed => &self.remove,
+            Kind::Bumped => &self.bump,
+            Kind::Dropped => &self.drop,
+            Kind::Changed => &self.change,
+            Kind::NotationOnly => &self.notation,
+            Kind::SourceChanged { .. } => &self.source,
+            Kind::Skipped(_) => &self.skip,
+        };
+
+        pattern.render(change, self.style)
+    }
+}
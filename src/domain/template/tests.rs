@@ -0,0 +1,157 @@
+use assert_fs::{prelude::FileWriteStr, NamedTempFile};
+
+use super::Template;
+use crate::domain::{
+    cargo_toml::Span,
+    change_set::{DependencyChange, Kind, Section},
+    semver::Change,
+    Style,
+};
+
+fn bumped_change() -> DependencyChange {
+    DependencyChange {
+        section: Section::DevDependencies,
+        name: String::from("serde_renamed"),
+        package: String::from("serde"),
+        previous_requirement: Some(String::from("1.0.200")),
+        current_requirement: Some(String::from("1.0.215")),
+        kind: Kind::Bumped,
+        change: Change::Patch,
+        relationship: None,
+        bounds: None,
+        current_span: None,
+        previous_span: None,
+    }
+}
+
+#[test]
+fn builtin_template_renders_emoji_report_line() {
+    // act
+    let result = Template::builtin(Style::Emoji).render_change(&bumped_change());
+
+    // assert
+    assert_eq!(
+        result,
+        String::from("🔧 bump serde (🖥\u{fe0f} dev-dependencies) from 1.0.200 to 1.0.215 ()")
+    );
+    assert_eq!(
+        Template::builtin(Style::Emoji).no_changes,
+        String::from("🧹 No changes detected.\n")
+    );
+}
+
+#[test]
+fn builtin_plain_template_renders_words() {
+    // act
+    let result = Template::builtin(Style::Plain).render_change(&bumped_change());
+
+    // assert
+    assert_eq!(
+        result,
+        String::from("PATCH bump serde (dev-dependencies) from 1.0.200 to 1.0.215 ()")
+    );
+    assert_eq!(
+        Template::builtin(Style::Plain).no_changes,
+        String::from("No changes detected.\n")
+    );
+}
+
+#[test]
+fn new_from_str_falls_back_to_builtin_template_for_missing_keys() {
+    // arrange
+    let template_str = r#"header = "Dependency changes:\n"
+
+[change]
+bump = "{{ severity }}|{{direction}}|{{name}}|{{package}}|{{section}}|{{old}}|{{new}}"
+"#;
+
+    // act
+    let template = Template::new_from_str(template_str, Style::Emoji).unwrap();
+
+    // assert
+    assert_eq!(template.header, String::from("Dependency changes:\n"));
+    assert_eq!(template.footer, String::new());
+    assert_eq!(
+        template.render_change(&bumped_change()),
+        String::from("patch|bump|serde_renamed|serde|dev-dependencies|1.0.200|1.0.215")
+    );
+    assert_eq!(
+        template.render_change(&DependencyChange {
+            kind: Kind::Added,
+            previous_requirement: None,
+            ..bumped_change()
+        }),
+        String::from("✨ add serde (🖥\u{fe0f} dev-dependencies) 1.0.215")
+    );
+}
+
+#[test]
+fn render_change_cites_current_or_previous_line() {
+    // arrange
+    let template =
+        Template::new_from_str("[change]\nbump = \"Cargo.toml:{{line}}\"", Style::Emoji).unwrap();
+    let span = |line| Span {
+        start: 0,
+        end: 0,
+        line,
+        column: 1,
+    };
+
+    // act
+    let bumped = template.render_change(&DependencyChange {
+        current_span: Some(span(42)),
+        previous_span: Some(span(40)),
+        ..bumped_change()
+    });
+    let removed = template.render_change(&DependencyChange {
+        previous_span: Some(span(40)),
+        ..bumped_change()
+    });
+
+    // assert
+    assert_eq!(bumped, String::from("Cargo.toml:42"));
+    assert_eq!(removed, String::from("Cargo.toml:40"));
+}
+
+#[test]
+fn new_from_str_returns_error_for_invalid_templates() {
+    // act
+    let unknown_variable =
+        Template::new_from_str("[change]\nadd = \"{{version}}\"", Style::Emoji).unwrap_err();
+    let unclosed = Template::new_from_str("[change]\nadd = \"{{name\"", Style::Emoji).unwrap_err();
+    let unknown_key = Template::new_from_str("title = \"\"", Style::Emoji).unwrap_err();
+
+    // assert
+    assert_eq!(
+        format!("{unknown_variable:#}"),
+        String::from(
+            "Error in `change.add` template: Unknown variable `version` in `{{version}}`.  \
+                Expected one of: name, package, section, label, old, new, old_requirement, \
+                new_requirement, severity, emoji, tag, direction, detail, reason, old_source, new_source, line"
+        )
+    );
+    assert_eq!(
+        format!("{unclosed:#}"),
+        String::from("Error in `change.add` template: Unclosed `{{` in `{{name`")
+    );
+    assert!(format!("{unknown_key:#}").starts_with("Creating `Template` from str"));
+}
+
+#[test]
+fn new_reads_template_file() {
+    // arrange
+    let template_file = NamedTempFile::new("template.toml").unwrap();
+    template_file
+        .write_str("footer = \"Done\\n\"\n[change]\nbump = \"{{label}}\"\n")
+        .unwrap();
+
+    // act
+    let template = Template::new(template_file.path(), Style::Emoji).unwrap();
+
+    // assert
+    assert_eq!(template.footer, String::from("Done\n"));
+    assert_eq!(
+        template.render_change(&bumped_change()),
+        String::from("serde (🖥\u{fe0f} dev-dependencies)")
+    );
+}
@@ -6,64 +6,468 @@ mod domain;
 #[cfg(test)]
 mod test_helpers;
 
-use std::path::Path;
+use std::{
+    env,
+    fmt::Write as _,
+    fs,
+    io::{self, IsTerminal},
+    path::Path,
+    process::ExitCode,
+};
 
 use anyhow::Context;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 
 use crate::{
-    cli::Cli,
-    domain::{CargoTomlFile, Repo},
+    cli::{Cli, Command, FailOn, OutputFormat},
+    domain::{
+        change_set::Kind,
+        changelog, lint,
+        semver::Change,
+        style::{Mark, Style},
+        CargoLockFile, CargoTomlFile, ChangeSet, Repo, Template,
+    },
 };
 
-fn get_rust_crate_diffs<P: AsRef<Path>>(repo_path: P) -> anyhow::Result<String> {
-    let repo = Repo::new(repo_path.as_ref()).with_context(|| {
+/// Exit code used when Cargo.lock includes source or checksum changes
+const SUPPLY_CHAIN_CHANGE_EXIT_CODE: u8 = 3;
+
+/// Exit code used when the `lint` command finds risky version requirements
+const LINT_FINDINGS_EXIT_CODE: u8 = 4;
+
+/// Exit code used when Cargo.toml includes a change matching a `--fail-on` policy
+const POLICY_FAILURE_EXIT_CODE: u8 = 5;
+
+/// Cargo.toml path used in CI annotations, relative to the repo root
+const CARGO_TOML_ANNOTATION_PATH: &str = "Cargo.toml";
+
+struct Diffs {
+    report: String,
+
+    /// Cargo.lock includes a package source or checksum change
+    supply_chain_changes: bool,
+
+    /// Cargo.toml includes a change matching a `--fail-on` policy
+    policy_failure: bool,
+}
+
+/// Returns `true` if any dependency change matches any of the `--fail-on` policies, logging each
+/// match
+fn fails_policy(change_set: &ChangeSet, fail_on: &[FailOn]) -> bool {
+    let mut result = false;
+    for policy in fail_on {
+        for change in &change_set.dependencies {
+            let matches = match policy {
+                FailOn::Major => change.is_at_least(&Change::Major),
+                FailOn::Minor => change.is_at_least(&Change::Minor),
+                FailOn::Patch => change.is_at_least(&Change::Patch),
+                FailOn::Downgrade => change.is_downgrade(),
+                FailOn::Add => change.kind == Kind::Added,
+                FailOn::Remove => change.kind == Kind::Removed,
+                FailOn::GitSource => change.is_git_source(),
+            };
+            if matches {
+                log::error!(
+                    "`--fail-on {}` matched {} [{}]",
+                    policy
+                        .to_possible_value()
+                        .map_or_else(String::new, |value| String::from(value.get_name())),
+                    change.package,
+                    change.section
+                );
+                result = true;
+            }
+        }
+    }
+
+    result
+}
+
+fn get_rust_crate_diffs<P: AsRef<Path>>(
+    repo_path: P,
+    hide_notation_changes: bool,
+    format: OutputFormat,
+    template: &Template,
+    fail_on: &[FailOn],
+) -> anyhow::Result<Diffs> {
+    let repo = open_repo(repo_path.as_ref())?;
+    let (latest_cargo_toml_file, original_cargo_toml_file) =
+        get_cargo_toml_files(&repo, repo_path.as_ref())?;
+    let cargo_lock_files = get_cargo_lock_files(&repo, repo_path.as_ref())?;
+
+    let mut change_set =
+        latest_cargo_toml_file.changes_versus_previous_version(&original_cargo_toml_file);
+    change_set.unsatisfiable_requirements = latest_cargo_toml_file
+        .unsatisfiable_requirements("working tree")
+        .into_iter()
+        .chain(original_cargo_toml_file.unsatisfiable_requirements("committed"))
+        .collect();
+    if let Some((latest_cargo_lock_file, Some(original_cargo_lock_file))) = &cargo_lock_files {
+        change_set.locked_version_changes = latest_cargo_lock_file.locked_version_changes(
+            &latest_cargo_toml_file,
+            original_cargo_lock_file,
+            &original_cargo_toml_file,
+        );
+        change_set.package_changes = latest_cargo_lock_file.package_changes(
+            &latest_cargo_toml_file,
+            original_cargo_lock_file,
+            &original_cargo_toml_file,
+        );
+        change_set.duplicate_changes = latest_cargo_lock_file.duplicate_changes(
+            &latest_cargo_toml_file,
+            original_cargo_lock_file,
+            &original_cargo_toml_file,
+        );
+        change_set.supply_chain_changes =
+            latest_cargo_lock_file.supply_chain_changes(original_cargo_lock_file);
+    }
+    let policy_failure = fails_policy(&change_set, fail_on);
+    let supply_chain_changes = !change_set.supply_chain_changes.is_empty();
+
+    let change_set_report = match format {
+        OutputFormat::Text => None,
+        OutputFormat::Json => Some(change_set.print_json(hide_notation_changes)?),
+        OutputFormat::Markdown => Some(change_set.print_markdown(hide_notation_changes)),
+        OutputFormat::CommitMessage => Some(change_set.print_commit_message(hide_notation_changes)),
+        OutputFormat::Github => Some(change_set.print_github_annotations(
+            CARGO_TOML_ANNOTATION_PATH,
+            hide_notation_changes,
+            template.style,
+        )),
+        OutputFormat::Gitlab => Some(change_set.print_gitlab_code_quality(
+            CARGO_TOML_ANNOTATION_PATH,
+            hide_notation_changes,
+            template.style,
+        )?),
+    };
+    if let Some(report) = change_set_report {
+        return Ok(Diffs {
+            report,
+            supply_chain_changes,
+            policy_failure,
+        });
+    }
+
+    let style = template.style;
+    let mut result = template.header.clone();
+    for requirement in &change_set.unsatisfiable_requirements {
+        let _ = writeln!(result, "{}", requirement.fmt_line(style));
+    }
+    let manifest_changes = change_set.print_changes(template, hide_notation_changes);
+    let lock_changes = print_cargo_lock_changes(
+        cargo_lock_files.as_ref(),
+        &latest_cargo_toml_file,
+        &original_cargo_toml_file,
+        &change_set,
+        style,
+    );
+    if manifest_changes.is_empty() && lock_changes.is_empty() {
+        result.push_str(&template.no_changes);
+    } else {
+        result.push_str(&manifest_changes);
+        result.push_str(&lock_changes);
+    }
+    result.push_str(&template.footer);
+
+    Ok(Diffs {
+        report: result,
+        supply_chain_changes,
+        policy_failure,
+    })
+}
+
+/// Text report lines for Cargo.lock changes, including the lock file changes in `change_set`
+fn print_cargo_lock_changes(
+    cargo_lock_files: Option<&(CargoLockFile, Option<CargoLockFile>)>,
+    latest_cargo_toml_file: &CargoTomlFile,
+    original_cargo_toml_file: &CargoTomlFile,
+    change_set: &ChangeSet,
+    style: Style,
+) -> String {
+    let mut result = String::new();
+
+    match cargo_lock_files {
+        Some((latest_cargo_lock_file, Some(original_cargo_lock_file))) => {
+            for change in &change_set.locked_version_changes {
+                let _ = writeln!(result, "{}", change.fmt_line(style));
+            }
+            result.push_str(&latest_cargo_lock_file.print_unchanged_lock_warning(
+                latest_cargo_toml_file,
+                original_cargo_lock_file,
+                original_cargo_toml_file,
+                style,
+            ));
+            result.push_str(&latest_cargo_lock_file.print_requirement_compatibility(
+                latest_cargo_toml_file,
+                original_cargo_toml_file,
+                style,
+            ));
+            for change in &change_set.supply_chain_changes {
+                let _ = writeln!(result, "{}", change.fmt_line(style));
+            }
+            for change in &change_set.package_changes {
+                let _ = writeln!(result, "{}", change.fmt_line(style));
+            }
+            for change in &change_set.duplicate_changes {
+                let _ = writeln!(result, "{}", change.fmt_line(style));
+            }
+        }
+        Some((latest_cargo_lock_file, None)) => {
+            result.push_str(&latest_cargo_lock_file.print_requirement_compatibility(
+                latest_cargo_toml_file,
+                original_cargo_toml_file,
+                style,
+            ));
+        }
+        None => {}
+    }
+
+    result
+}
+
+fn open_repo(repo_path: &Path) -> anyhow::Result<Repo> {
+    Repo::new(repo_path).with_context(|| {
         format!(
             "Failed to open repo at `{}`. Check the path is correct.",
-            repo_path.as_ref().display()
+            repo_path.display()
         )
-    })?;
+    })
+}
 
-    let cargo_toml_path = format!("{}/Cargo.toml", repo_path.as_ref().display());
+/// Returns the working tree and committed Cargo.toml files
+fn get_cargo_toml_files(
+    repo: &Repo,
+    repo_path: &Path,
+) -> anyhow::Result<(CargoTomlFile, CargoTomlFile)> {
+    let cargo_toml_path = format!("{}/Cargo.toml", repo_path.display());
     let latest_cargo_toml_file =
         CargoTomlFile::new(&cargo_toml_path).context("Open latest Cargo.toml file")?;
 
     let mut original_cargo_toml_buffer: Vec<u8> = Vec::new();
     repo.get_committed_cargo_toml(&mut original_cargo_toml_buffer)
         .context("Get committed Cargo.toml file")?;
-    let original_cargo_toml_file = CargoTomlFile::new_from_buffer(&original_cargo_toml_buffer)?;
+    let original_cargo_toml_file =
+        CargoTomlFile::new_from_buffer(&original_cargo_toml_buffer, "HEAD")?;
+
+    Ok((latest_cargo_toml_file, original_cargo_toml_file))
+}
+
+/// Adds Cargo.toml dependency changes to the `## [Unreleased]` section of the changelog at
+/// `changelog_path`, relative to the repo.  Returns the number of entries added.
+fn update_changelog(repo_path: &Path, changelog_path: &Path) -> anyhow::Result<usize> {
+    let repo = open_repo(repo_path)?;
+    let (latest_cargo_toml_file, original_cargo_toml_file) =
+        get_cargo_toml_files(&repo, repo_path)?;
+    let entries = latest_cargo_toml_file
+        .changes_versus_previous_version(&original_cargo_toml_file)
+        .changelog_entries();
+
+    let changelog_path = repo_path.join(changelog_path);
+    let changelog = fs::read_to_string(&changelog_path)
+        .with_context(|| format!("Error opening changelog: `{}`", changelog_path.display()))?;
+    let (updated_changelog, added) = changelog::add_dependency_entries(&changelog, &entries);
+    if added > 0 {
+        fs::write(&changelog_path, updated_changelog)
+            .with_context(|| format!("Error writing changelog: `{}`", changelog_path.display()))?;
+    }
+
+    Ok(added)
+}
+
+/// Lints dependencies in the working tree Cargo.toml, using the working tree Cargo.lock, if
+/// there is one, for locked versions.  Set `changed` to lint only dependencies added, or with a
+/// changed requirement, since the committed Cargo.toml.
+fn get_lint_findings(repo_path: &Path, changed: bool) -> anyhow::Result<Vec<lint::Finding>> {
+    let cargo_toml_path = format!("{}/Cargo.toml", repo_path.display());
+    let cargo_toml_file =
+        CargoTomlFile::new(&cargo_toml_path).context("Open latest Cargo.toml file")?;
+
+    let cargo_lock_path = repo_path.join("Cargo.lock");
+    let cargo_lock_file = if fs::exists(&cargo_lock_path).unwrap_or(false) {
+        Some(
+            CargoLockFile::new(&format!("{}", cargo_lock_path.display()))
+                .context("Open latest Cargo.lock file")?,
+        )
+    } else {
+        log::info!("No Cargo.lock found in working tree, so skipping locked version checks");
+        None
+    };
+
+    if changed {
+        let repo = open_repo(repo_path)?;
+        let (_, original_cargo_toml_file) = get_cargo_toml_files(&repo, repo_path)?;
+        return Ok(lint::lint_changed(
+            &cargo_toml_file,
+            &original_cargo_toml_file,
+            cargo_lock_file.as_ref(),
+        ));
+    }
 
-    latest_cargo_toml_file.print_changes_versus_previous_version(&original_cargo_toml_file)
+    Ok(lint::lint_manifest(
+        &cargo_toml_file,
+        cargo_lock_file.as_ref(),
+    ))
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Returns the working tree Cargo.lock file, with the committed one if there is one, or `None`
+/// if there is no Cargo.lock in the working tree
+fn get_cargo_lock_files(
+    repo: &Repo,
+    repo_path: &Path,
+) -> anyhow::Result<Option<(CargoLockFile, Option<CargoLockFile>)>> {
+    let cargo_lock_path = repo_path.join("Cargo.lock");
+    if !fs::exists(&cargo_lock_path).unwrap_or(false) {
+        log::info!("No Cargo.lock found in working tree, so skipping lock file changes");
+        return Ok(None);
+    }
+    let latest_cargo_lock_file = CargoLockFile::new(&format!("{}", cargo_lock_path.display()))
+        .context("Open latest Cargo.lock file")?;
+
+    let mut original_cargo_lock_buffer: Vec<u8> = Vec::new();
+    if !repo
+        .get_committed_cargo_lock(&mut original_cargo_lock_buffer)
+        .context("Get committed Cargo.lock file")?
+    {
+        log::info!("No committed Cargo.lock found, so skipping lock file changes");
+        return Ok(Some((latest_cargo_lock_file, None)));
+    }
+    let original_cargo_lock_file = CargoLockFile::new_from_buffer(&original_cargo_lock_buffer)?;
+
+    Ok(Some((
+        latest_cargo_lock_file,
+        Some(original_cargo_lock_file),
+    )))
+}
+
+/// Emoji are used only when stdout is a terminal with a UTF-8 locale, unless overridden by the
+/// `--plain` or `--emoji` flag
+fn get_style(cli: &Cli) -> Style {
+    if cli.plain {
+        return Style::Plain;
+    }
+    if cli.emoji {
+        return Style::Emoji;
+    }
+    let utf8_locale = cfg!(windows)
+        || ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .is_some_and(|locale| {
+                let locale = locale.to_lowercase();
+                locale.contains("utf-8") || locale.contains("utf8")
+            });
+
+    if io::stdout().is_terminal() && utf8_locale {
+        Style::Emoji
+    } else {
+        Style::Plain
+    }
+}
+
+fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
     let cli = &Cli::parse();
     env_logger::Builder::new()
         .filter_level(cli.verbose.log_level_filter())
         .init();
 
-    let repo_path = &cli.repo_path;
+    let style = get_style(cli);
+
+    if let Some(Command::Lint { repo_path, changed }) = &cli.command {
+        let findings = get_lint_findings(repo_path, *changed)?;
+        if findings.is_empty() {
+            println!(
+                "{}No risky version requirements found.",
+                style.mark(Mark::Clean)
+            );
+            return Ok(ExitCode::SUCCESS);
+        }
+        print!("{}", lint::print_findings(&findings, style));
+        return Ok(ExitCode::from(LINT_FINDINGS_EXIT_CODE));
+    }
+
+    if let Some(Command::Changelog {
+        repo_path,
+        changelog,
+    }) = &cli.command
+    {
+        let added = update_changelog(repo_path, changelog)?;
+        if added == 0 {
+            println!(
+                "{}{} already lists every change.",
+                style.mark(Mark::Clean),
+                changelog.display()
+            );
+        } else {
+            println!(
+                "{} add {added} dependency change{} to {}",
+                style.mark(Mark::Changelog),
+                if added == 1 { "" } else { "s" },
+                changelog.display()
+            );
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let repo_path = cli.repo_path.as_ref().context("Missing repo path")?;
+    let custom_template = cli
+        .template
+        .as_deref()
+        .map(|path| Template::new(path, style))
+        .transpose()?;
+    let template = custom_template.as_ref().unwrap_or(Template::builtin(style));
 
-    let output = get_rust_crate_diffs(repo_path)?;
-    for line in output.lines() {
+    let Diffs {
+        report,
+        supply_chain_changes,
+        policy_failure,
+    } = get_rust_crate_diffs(
+        repo_path,
+        cli.hide_notation_changes,
+        cli.format,
+        template,
+        &cli.fail_on,
+    )?;
+    for line in report.lines() {
         println!("{line}");
     }
 
-    Ok(())
+    // Supply-chain changes take precedence over policy failures, as documented in `EXIT_CODES`
+    if supply_chain_changes {
+        return Ok(ExitCode::from(SUPPLY_CHAIN_CHANGE_EXIT_CODE));
+    }
+    if policy_failure {
+        return Ok(ExitCode::from(POLICY_FAILURE_EXIT_CODE));
+    }
+
+    Ok(ExitCode::SUCCESS)
 }
 
 #[cfg(test)]
 mod tests {
-    use std::fs::{self};
+    use std::{
+        fs::{self},
+        path::Path,
+    };
 
-    use super::test_helpers::create_temporary_repo_with_committed_file;
-    use crate::get_rust_crate_diffs;
+    use super::test_helpers::{
+        create_temporary_repo_with_committed_cargo_toml_and_lock,
+        create_temporary_repo_with_committed_file,
+    };
+    use crate::{
+        cli::{FailOn, OutputFormat},
+        domain::{Style, Template},
+        get_lint_findings, get_rust_crate_diffs, update_changelog,
+    };
 
     /// Runs snapshots again input Cargo file in `src/snapshot_inputs`.  Requires a pair of input
     /// files to exist for each test:
     ///
     ///     - `src/snapshot_inputs/<some_stem>_repo.toml`; and
     ///     - `src/snapshot_inputs/<some_stem>_local.toml`.
+    ///
+    /// Optionally, a pair of `<some_stem>_repo.lock` and `<some_stem>_local.lock` Cargo.lock
+    /// files can be included too.
     #[test]
     fn get_rust_crate_diffs_returns_expected_result() {
         insta::glob!(
@@ -77,11 +481,29 @@ mod tests {
                 let input_local_cargo_toml_path = format!("{path_stem}_local.toml");
                 assert!(fs::exists(&input_local_cargo_toml_path).is_ok());
 
+                let input_repo_cargo_lock_path = format!("{path_stem}_repo.lock");
+                let input_local_cargo_lock_path = format!("{path_stem}_local.lock");
+
                 let temp_dir = assert_fs::TempDir::new().unwrap();
-                let (repo_path, cargo_toml_path) = create_temporary_repo_with_committed_file(
-                    &temp_dir,
-                    input_repo_cargo_toml_path,
-                );
+                let (repo_path, cargo_toml_path) = if fs::exists(&input_repo_cargo_lock_path)
+                    .unwrap()
+                {
+                    let (repo_path, cargo_toml_path, cargo_lock_path) =
+                        create_temporary_repo_with_committed_cargo_toml_and_lock(
+                            &temp_dir,
+                            input_repo_cargo_toml_path.to_str().unwrap(),
+                            &input_repo_cargo_lock_path,
+                        );
+
+                    // make changes to the on-disk Cargo.lock
+                    let local_cargo_lock_content =
+                        fs::read_to_string(&input_local_cargo_lock_path).unwrap();
+                    let () = fs::write(&cargo_lock_path, local_cargo_lock_content).unwrap();
+
+                    (repo_path, cargo_toml_path)
+                } else {
+                    create_temporary_repo_with_committed_file(&temp_dir, input_repo_cargo_toml_path)
+                };
 
                 // make changes to the on-disk Cargo.toml
                 let local_cargo_toml_content =
@@ -89,11 +511,313 @@ mod tests {
                 let () = fs::write(&cargo_toml_path, local_cargo_toml_content).unwrap();
 
                 // act
-                let result = get_rust_crate_diffs(repo_path).unwrap();
+                let result = get_rust_crate_diffs(
+                    repo_path,
+                    false,
+                    OutputFormat::Text,
+                    Template::builtin(Style::Emoji),
+                    &[],
+                )
+                .unwrap();
 
                 // assert
-                insta::assert_snapshot!(result);
+                insta::assert_snapshot!(result.report);
             }
         );
     }
+
+    #[test]
+    fn get_rust_crate_diffs_checks_requirements_against_uncommitted_lock() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let (repo_path, cargo_toml_path) = create_temporary_repo_with_committed_file(
+            &temp_dir,
+            "src/snapshot_inputs/cargo_lock_repo.toml",
+        );
+        let () = fs::write(
+            &cargo_toml_path,
+            fs::read_to_string("src/snapshot_inputs/cargo_lock_local.toml").unwrap(),
+        )
+        .unwrap();
+        let () = fs::write(
+            repo_path.join("Cargo.lock"),
+            fs::read_to_string("src/snapshot_inputs/cargo_lock_local.lock").unwrap(),
+        )
+        .unwrap();
+
+        // act
+        let result = get_rust_crate_diffs(
+            &repo_path,
+            false,
+            OutputFormat::Text,
+            Template::builtin(Style::Emoji),
+            &[],
+        )
+        .unwrap();
+
+        // assert
+        assert!(result
+            .report
+            .contains("🔐 lock satisfies new requirement serde 1.0.215: locked 1.0.217\n"));
+    }
+
+    #[test]
+    fn get_rust_crate_diffs_flags_supply_chain_changes() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let (repo_path, _cargo_toml_path, cargo_lock_path) =
+            create_temporary_repo_with_committed_cargo_toml_and_lock(
+                &temp_dir,
+                "src/snapshot_inputs/cargo_lock_repo.toml",
+                "src/snapshot_inputs/cargo_lock_repo.lock",
+            );
+        let cargo_lock_content = fs::read_to_string(&cargo_lock_path).unwrap().replace(
+            "source = \"registry+https://github.com/rust-lang/crates.io-index\"\nchecksum = \"c8e3",
+            "source = \"registry+https://example.com/other-index\"\nchecksum = \"c8e3",
+        );
+        let () = fs::write(&cargo_lock_path, cargo_lock_content).unwrap();
+
+        // act
+        let result = get_rust_crate_diffs(
+            &repo_path,
+            false,
+            OutputFormat::Text,
+            Template::builtin(Style::Emoji),
+            &[],
+        )
+        .unwrap();
+        let json_result = get_rust_crate_diffs(
+            &repo_path,
+            false,
+            OutputFormat::Json,
+            Template::builtin(Style::Emoji),
+            &[],
+        )
+        .unwrap();
+
+        // assert
+        assert!(result.supply_chain_changes);
+        assert!(result.report.contains(
+            "🛑 change source serde (🔒 Cargo.lock) 1.0.210 from \
+                registry+https://github.com/rust-lang/crates.io-index to \
+                registry+https://example.com/other-index\n"
+        ));
+        assert!(json_result.supply_chain_changes);
+        let report: serde_json::Value = serde_json::from_str(&json_result.report).unwrap();
+        assert_eq!(
+            report["supply_chain_changes"],
+            serde_json::json!([{
+                "package": "serde",
+                "version": "1.0.210",
+                "field": "source",
+                "previous": "registry+https://github.com/rust-lang/crates.io-index",
+                "current": "registry+https://example.com/other-index"
+            }])
+        );
+    }
+
+    #[test]
+    fn get_rust_crate_diffs_returns_json_when_requested() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let (repo_path, cargo_toml_path) = create_temporary_repo_with_committed_file(
+            &temp_dir,
+            "src/snapshot_inputs/cargo_toml_repo.toml",
+        );
+        let cargo_toml_content =
+            fs::read_to_string("src/snapshot_inputs/cargo_toml_local.toml").unwrap();
+        let () = fs::write(&cargo_toml_path, cargo_toml_content).unwrap();
+
+        // act
+        let result = get_rust_crate_diffs(
+            &repo_path,
+            false,
+            OutputFormat::Json,
+            Template::builtin(Style::Emoji),
+            &[],
+        )
+        .unwrap();
+
+        // assert
+        let report: serde_json::Value = serde_json::from_str(&result.report).unwrap();
+        assert_eq!(report["schema_version"], 1);
+        assert!(!report["changes"].as_array().unwrap().is_empty());
+        assert!(!result.supply_chain_changes);
+    }
+
+    #[test]
+    fn get_rust_crate_diffs_returns_lock_file_changes_in_json() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let (repo_path, cargo_toml_path, cargo_lock_path) =
+            create_temporary_repo_with_committed_cargo_toml_and_lock(
+                &temp_dir,
+                "src/snapshot_inputs/cargo_lock_repo.toml",
+                "src/snapshot_inputs/cargo_lock_repo.lock",
+            );
+        let () = fs::write(
+            &cargo_toml_path,
+            fs::read_to_string("src/snapshot_inputs/cargo_lock_local.toml").unwrap(),
+        )
+        .unwrap();
+        let () = fs::write(
+            &cargo_lock_path,
+            fs::read_to_string("src/snapshot_inputs/cargo_lock_local.lock").unwrap(),
+        )
+        .unwrap();
+
+        // act
+        let result = get_rust_crate_diffs(
+            &repo_path,
+            false,
+            OutputFormat::Json,
+            Template::builtin(Style::Emoji),
+            &[],
+        )
+        .unwrap();
+
+        // assert
+        let schema: serde_json::Value =
+            serde_json::from_str(include_str!("../schema/changes.schema.json")).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&result.report).unwrap();
+        jsonschema::validate(&schema, &report).unwrap();
+        assert_eq!(
+            report["locked_version_changes"],
+            serde_json::json!([
+                {
+                    "package": "log",
+                    "previous_requirement": "0.4.20",
+                    "current_requirement": "0.4.20",
+                    "previous_locked": "0.4.20",
+                    "current_locked": "0.4.22"
+                },
+                {
+                    "package": "serde",
+                    "previous_requirement": "1.0.200",
+                    "current_requirement": "1.0.215",
+                    "previous_locked": "1.0.210",
+                    "current_locked": "1.0.217"
+                }
+            ])
+        );
+        assert_eq!(report["package_changes"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn get_rust_crate_diffs_applies_fail_on_policies() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let (repo_path, cargo_toml_path) = create_temporary_repo_with_committed_file(
+            &temp_dir,
+            "src/snapshot_inputs/cargo_toml_repo.toml",
+        );
+        let cargo_toml_content =
+            fs::read_to_string("src/snapshot_inputs/cargo_toml_local.toml").unwrap();
+        let () = fs::write(&cargo_toml_path, cargo_toml_content).unwrap();
+
+        // act
+        let major = get_rust_crate_diffs(
+            &repo_path,
+            false,
+            OutputFormat::Text,
+            Template::builtin(Style::Emoji),
+            &[FailOn::Major, FailOn::GitSource],
+        )
+        .unwrap();
+        let downgrade = get_rust_crate_diffs(
+            &repo_path,
+            false,
+            OutputFormat::Text,
+            Template::builtin(Style::Emoji),
+            &[FailOn::Downgrade],
+        )
+        .unwrap();
+        let add_json = get_rust_crate_diffs(
+            &repo_path,
+            false,
+            OutputFormat::Json,
+            Template::builtin(Style::Emoji),
+            &[FailOn::Add],
+        )
+        .unwrap();
+
+        // assert
+        assert!(!major.policy_failure);
+        assert!(downgrade.policy_failure);
+        assert!(add_json.policy_failure);
+    }
+
+    #[test]
+    fn update_changelog_adds_each_entry_once() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let (repo_path, cargo_toml_path) = create_temporary_repo_with_committed_file(
+            &temp_dir,
+            "src/snapshot_inputs/cargo_toml_repo.toml",
+        );
+        let cargo_toml_content =
+            fs::read_to_string("src/snapshot_inputs/cargo_toml_local.toml").unwrap();
+        let () = fs::write(&cargo_toml_path, cargo_toml_content).unwrap();
+        let changelog_path = repo_path.join("CHANGELOG.md");
+        let () = fs::write(&changelog_path, "# Changelog\n").unwrap();
+
+        // act
+        let added = update_changelog(&repo_path, Path::new("CHANGELOG.md")).unwrap();
+        let rerun_added = update_changelog(&repo_path, Path::new("CHANGELOG.md")).unwrap();
+
+        // assert
+        assert_eq!(added, 4);
+        assert_eq!(rerun_added, 0);
+        assert_eq!(
+            fs::read_to_string(&changelog_path).unwrap(),
+            "# Changelog
+
+## [Unreleased]
+
+### Dependencies
+
+- drop ahash from 0.8.10 to 0.8
+- drop clap-verbosity-flag from 3.0.1 to 3.0.0
+- add serde 1.0.215
+- remove git2 0.19.0
+"
+        );
+    }
+
+    #[test]
+    fn get_lint_findings_only_lints_changed_dependencies_when_requested() {
+        // arrange
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let committed_cargo_toml_path = temp_dir.join("committed.toml");
+        let committed_cargo_toml = "[package]
+name = \"some-example-crate\"
+version = \"0.1.0\"
+
+[dependencies]
+glob = \"*\"
+log = \"0.4.22\"
+";
+        let () = fs::write(&committed_cargo_toml_path, committed_cargo_toml).unwrap();
+        let (repo_path, cargo_toml_path) =
+            create_temporary_repo_with_committed_file(&temp_dir, &committed_cargo_toml_path);
+        let () = fs::write(
+            &cargo_toml_path,
+            committed_cargo_toml.replace("\"0.4.22\"", "\"=0.4.22\""),
+        )
+        .unwrap();
+
+        // act
+        let all_findings = get_lint_findings(&repo_path, false).unwrap();
+        let changed_findings = get_lint_findings(&repo_path, true).unwrap();
+
+        // assert
+        let packages = |findings: &[crate::domain::lint::Finding]| {
+            findings
+                .iter()
+                .map(|finding| finding.package.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(packages(&all_findings), vec!["glob", "log"]);
+        assert_eq!(packages(&changed_findings), vec!["log"]);
+    }
 }
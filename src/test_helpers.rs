@@ -6,20 +6,23 @@ use std::{
 use assert_fs::TempDir;
 use git2::{Repository, Signature};
 
-pub fn create_temporary_repo_with_committed_file<P: AsRef<Path>>(
+fn create_temporary_repo_with_committed_files(
     temp_dir: &TempDir,
-    commit_config_toml_path: P,
-) -> (PathBuf, PathBuf) {
+    files: &[(&str, &Path)],
+) -> PathBuf {
     let repo_path = temp_dir.path().join("test-repo");
     let underlying_repo = Repository::init(&repo_path).unwrap();
-    let cargo_toml_path = repo_path.join("Cargo.toml");
-    let repo_cargo_toml_content = fs::read_to_string(commit_config_toml_path).unwrap();
-    let () = fs::write(&cargo_toml_path, repo_cargo_toml_content).unwrap();
+    for (file_name, commit_file_path) in files {
+        let repo_file_content = fs::read_to_string(commit_file_path).unwrap();
+        let () = fs::write(repo_path.join(file_name), repo_file_content).unwrap();
+    }
 
-    // Create a git repo on-disk and add Cargo.toml to it, then commit the change
+    // Create a git repo on-disk and add files to it, then commit the change
     let tree_id = {
         let mut index = underlying_repo.index().unwrap();
-        let _ = index.add_path(&PathBuf::from("Cargo.toml"));
+        for (file_name, _) in files {
+            let _ = index.add_path(&PathBuf::from(file_name));
+        }
         index.write().unwrap();
         index.write_tree().unwrap()
     };
@@ -36,5 +39,37 @@ pub fn create_temporary_repo_with_committed_file<P: AsRef<Path>>(
         )
         .unwrap();
 
+    repo_path
+}
+
+pub fn create_temporary_repo_with_committed_file<P: AsRef<Path>>(
+    temp_dir: &TempDir,
+    commit_config_toml_path: P,
+) -> (PathBuf, PathBuf) {
+    let repo_path = create_temporary_repo_with_committed_files(
+        temp_dir,
+        &[("Cargo.toml", commit_config_toml_path.as_ref())],
+    );
+    let cargo_toml_path = repo_path.join("Cargo.toml");
+
     (repo_path, cargo_toml_path)
 }
+
+/// Returns paths for the repo, Cargo.toml and Cargo.lock
+pub fn create_temporary_repo_with_committed_cargo_toml_and_lock<P: AsRef<Path>>(
+    temp_dir: &TempDir,
+    commit_config_toml_path: P,
+    commit_cargo_lock_path: P,
+) -> (PathBuf, PathBuf, PathBuf) {
+    let repo_path = create_temporary_repo_with_committed_files(
+        temp_dir,
+        &[
+            ("Cargo.toml", commit_config_toml_path.as_ref()),
+            ("Cargo.lock", commit_cargo_lock_path.as_ref()),
+        ],
+    );
+    let cargo_toml_path = repo_path.join("Cargo.toml");
+    let cargo_lock_path = repo_path.join("Cargo.lock");
+
+    (repo_path, cargo_toml_path, cargo_lock_path)
+}